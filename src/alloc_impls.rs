@@ -1,20 +1,58 @@
 use crate::NoStdCow;
 use alloc::borrow::{Cow, ToOwned};
+use alloc::boxed::Box;
+use alloc::collections::TryReserveError;
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::borrow::Borrow;
-impl<'a, B: ?Sized> NoStdCow<'a, <B as ToOwned>::Owned, B>
-where
-    <B as ToOwned>::Owned: Borrow<B>,
-    B: Clone,
-{
+use core::fmt;
+use core::ops::AddAssign;
+use core::str::Utf8Error;
+impl<'a, B: ?Sized + ToOwned> NoStdCow<'a, <B as ToOwned>::Owned, B> {
     /// Convert this [`NoStdCow`] into a [`alloc::borrow::Cow`].
     /// 
     /// A [`From`] implementation is also available.
+    ///
+    /// # Example
+    /// ```
+    /// use std::borrow::Cow;
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let cow: NoStdCow<'_, String, str> = NoStdCow::Borrowed("hello");
+    /// assert!(matches!(cow.into_alloc_cow(), Cow::Borrowed("hello")));
+    /// ```
     pub fn into_alloc_cow(self) -> Cow<'a, B> {
         match self {
             Self::Borrowed(b) => Cow::Borrowed(b),
             Self::Owned(o) => Cow::Owned(o)
         }
     }
+    /// Create a [`alloc::borrow::Cow`] from a reference to this [`NoStdCow`].
+    ///
+    /// Unlike [`NoStdCow::into_alloc_cow`] this doesn't consume the cow, so owned
+    /// data has to be cloned with [`ToOwned::to_owned`]. Borrowed data is only
+    /// copied by reference.
+    ///
+    /// # Example
+    /// ```
+    /// use std::borrow::Cow;
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("hello");
+    /// assert!(matches!(borrowed.to_alloc_cow(), Cow::Borrowed("hello")));
+    ///
+    /// let owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("hello"));
+    /// let cow = owned.to_alloc_cow();
+    /// assert!(matches!(&cow, Cow::Owned(s) if s == "hello"));
+    /// // The original cow is still usable.
+    /// assert!(owned.is_owned());
+    /// ```
+    pub fn to_alloc_cow(&self) -> Cow<'a, B> {
+        match self {
+            Self::Borrowed(b) => Cow::Borrowed(b),
+            Self::Owned(o) => Cow::Owned(o.borrow().to_owned()),
+        }
+    }
     /// Convert a [`alloc::borrow::Cow`] into a [`NoStdCow`].
     /// 
     /// A [`From`] implementation is also available.
@@ -26,21 +64,917 @@ where
     }
 }
 
-impl<'a, B: ?Sized> From<Cow<'a, B>> for NoStdCow<'a, <B as ToOwned>::Owned, B>
-where
-    <B as ToOwned>::Owned: Borrow<B>,
-    B: Clone
-{
+impl<'a, B: ?Sized + ToOwned> From<Cow<'a, B>> for NoStdCow<'a, <B as ToOwned>::Owned, B> {
     fn from(value: Cow<'a, B>) -> Self {
         Self::from_alloc_cow(value)
     }
 }
-impl<'a, B: ?Sized> From<NoStdCow<'a, <B as ToOwned>::Owned, B>> for Cow<'a, B>
-where
-    <B as ToOwned>::Owned: Borrow<B>,
-    B: Clone
-{
+impl<'a, B: ?Sized + ToOwned> From<NoStdCow<'a, <B as ToOwned>::Owned, B>> for Cow<'a, B> {
     fn from(value: NoStdCow<'a, <B as ToOwned>::Owned, B>) -> Self {
         value.into_alloc_cow()
     }
-}
\ No newline at end of file
+}
+
+impl NoStdCow<'static, String, str> {
+    /// Build an owned string cow from [`format_args!`] output.
+    ///
+    /// Formatting always produces a fresh [`String`], so the result is always
+    /// [`NoStdCow::Owned`]. A [`From`] implementation is also available.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let (a, b) = (1, "two");
+    /// let cow = NoStdCow::from_fmt(format_args!("{}-{}", a, b));
+    /// assert_eq!(cow, NoStdCow::Owned(String::from("1-two")));
+    /// ```
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Self {
+        Self::Owned(alloc::fmt::format(args))
+    }
+    /// Converts the cow into a `'static` string slice, leaking an owned [`String`].
+    ///
+    /// Borrowed data is already `'static` and is returned as is. For an owned value
+    /// the allocation is intentionally leaked with [`String::leak`] and will never be
+    /// freed, so only use this for data that must live for the rest of the program.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let owned: NoStdCow<'static, String, str> = NoStdCow::Owned(String::from("leaked"));
+    /// let leaked: &'static str = owned.leak();
+    /// assert_eq!(leaked, "leaked");
+    ///
+    /// let borrowed: NoStdCow<'static, String, str> = NoStdCow::Borrowed("static");
+    /// assert_eq!(borrowed.leak(), "static");
+    /// ```
+    pub fn leak(self) -> &'static str {
+        match self {
+            Self::Borrowed(b) => b,
+            Self::Owned(o) => o.leak(),
+        }
+    }
+}
+
+impl From<fmt::Arguments<'_>> for NoStdCow<'static, String, str> {
+    fn from(value: fmt::Arguments<'_>) -> Self {
+        Self::from_fmt(value)
+    }
+}
+
+/// Builds an owned single-character string cow, like `String: From<char>`.
+///
+/// Without `alloc`, encode the character into a buffer with [`char::encode_utf8`]
+/// and wrap the result in [`NoStdCow::Borrowed`] instead.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// assert_eq!(NoStdCow::from('a'), NoStdCow::Owned(String::from("a")));
+/// assert_eq!(NoStdCow::from('é'), NoStdCow::Owned(String::from("é")));
+/// assert_eq!(NoStdCow::from('🦀').len(), 4);
+/// ```
+impl From<char> for NoStdCow<'static, String, str> {
+    fn from(value: char) -> Self {
+        Self::Owned(String::from(value))
+    }
+}
+
+/// Collects characters into an owned string cow.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let cow: NoStdCow<'_, String, str> = "a-b-c".chars().filter(|c| *c != '-').collect();
+/// assert_eq!(cow, NoStdCow::Owned(String::from("abc")));
+/// ```
+impl FromIterator<char> for NoStdCow<'_, String, str> {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        Self::Owned(String::from_iter(iter))
+    }
+}
+
+impl<U: Clone> NoStdCow<'_, Vec<U>, [U]> {
+    /// Acquires a mutable reference to the owned [`Vec`], cloning the slice if it is borrowed.
+    fn owned_vec_mut(&mut self) -> &mut Vec<U> {
+        if let Self::Borrowed(b) = *self {
+            *self = Self::Owned(b.to_vec());
+        }
+        match self {
+            Self::Borrowed(_) => unreachable!(),
+            Self::Owned(v) => v,
+        }
+    }
+    /// Converts the cow into one that is guaranteed to be [`NoStdCow::Owned`].
+    ///
+    /// Clones the slice if it is borrowed. Since the result no longer borrows
+    /// anything it can have any lifetime, including `'static`.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2]);
+    /// let owned: NoStdCow<'static, Vec<u8>, [u8]> = borrowed.into_owned_cow();
+    /// assert_eq!(owned, NoStdCow::Owned(vec![1, 2]));
+    /// ```
+    pub fn into_owned_cow<'b>(self) -> NoStdCow<'b, Vec<U>, [U]> {
+        NoStdCow::Owned(Vec::from(self))
+    }
+    /// Acquires a mutable reference to the slice, cloning it into an owned [`Vec`]
+    /// if it is borrowed.
+    ///
+    /// Unlike `to_mut`, which needs `B == T`, this returns the unsized `&mut [U]`
+    /// through the [`DerefMut`](core::ops::DerefMut) of the owned [`Vec`]. Requires
+    /// the `alloc` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+    /// borrowed.to_mut_b()[0] = 9;
+    /// assert_eq!(borrowed, NoStdCow::Owned(vec![9, 2, 3]));
+    ///
+    /// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+    /// owned.to_mut_b().reverse();
+    /// assert_eq!(owned, NoStdCow::Owned(vec![3, 2, 1]));
+    /// ```
+    pub fn to_mut_b(&mut self) -> &mut [U] {
+        self.owned_vec_mut()
+    }
+    /// Splits the contents in two at `at`, like [`Vec::split_off`].
+    ///
+    /// `self` keeps the elements before `at` and the rest is returned as an owned
+    /// cow, reusing the owned [`Vec`] for the prefix. A borrowed slice is cloned into
+    /// an owned [`Vec`] once before splitting.
+    ///
+    /// # Panics
+    /// Panics if `at` is greater than the length of the slice.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3, 4]);
+    /// let tail = borrowed.split_off(1);
+    /// assert_eq!(borrowed, NoStdCow::Owned(vec![1]));
+    /// assert_eq!(tail, NoStdCow::Owned(vec![2, 3, 4]));
+    /// assert!(borrowed.is_owned() && tail.is_owned());
+    ///
+    /// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+    /// let ptr = owned.as_ptr();
+    /// assert!(owned.split_off(3).is_empty());
+    /// assert_eq!(owned.as_ptr(), ptr);
+    /// assert_eq!(owned.split_off(0), NoStdCow::Owned(vec![1, 2, 3]));
+    /// assert!(owned.is_empty());
+    /// ```
+    /// ```should_panic
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut cow: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2]);
+    /// cow.split_off(3);
+    /// ```
+    pub fn split_off<'b>(&mut self, at: usize) -> NoStdCow<'b, Vec<U>, [U]> {
+        NoStdCow::Owned(self.owned_vec_mut().split_off(at))
+    }
+    /// Appends an element to the end, like [`Vec::push`].
+    ///
+    /// A borrowed slice is cloned into an owned [`Vec`] once before pushing.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2]);
+    /// borrowed.push(3);
+    /// assert_eq!(borrowed, [1u8, 2, 3]);
+    /// assert!(borrowed.is_owned());
+    ///
+    /// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1]);
+    /// owned.push(2);
+    /// assert_eq!(owned, [1u8, 2]);
+    /// ```
+    pub fn push(&mut self, value: U) {
+        self.owned_vec_mut().push(value)
+    }
+    /// Retains only the elements specified by the predicate, like [`Vec::retain`].
+    ///
+    /// A borrowed slice is cloned into an owned [`Vec`] once before filtering.
+    ///
+    /// # Example
+    /// ```
+    /// use core::sync::atomic::{AtomicUsize, Ordering};
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// static CLONES: AtomicUsize = AtomicUsize::new(0);
+    /// #[derive(Debug, PartialEq)]
+    /// struct Counted(u8);
+    /// impl Clone for Counted {
+    ///     fn clone(&self) -> Self {
+    ///         CLONES.fetch_add(1, Ordering::SeqCst);
+    ///         Counted(self.0)
+    ///     }
+    /// }
+    ///
+    /// let data = [Counted(1), Counted(2), Counted(3)];
+    /// let mut borrowed: NoStdCow<'_, Vec<Counted>, [Counted]> = NoStdCow::Borrowed(&data);
+    /// borrowed.retain(|c| c.0 != 2);
+    /// assert_eq!(&*borrowed, &[Counted(1), Counted(3)]);
+    /// // Every element was cloned exactly once.
+    /// assert_eq!(CLONES.load(Ordering::SeqCst), 3);
+    ///
+    /// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3, 4]);
+    /// owned.retain(|x| x % 2 == 0);
+    /// assert_eq!(owned, NoStdCow::Owned(vec![2, 4]));
+    /// ```
+    pub fn retain<F: FnMut(&U) -> bool>(&mut self, pred: F) {
+        self.owned_vec_mut().retain(pred)
+    }
+    /// Rotates the elements in place so the element at `mid` becomes the first,
+    /// like [`slice::rotate_left`](https://doc.rust-lang.org/core/primitive.slice.html#method.rotate_left).
+    ///
+    /// A borrowed slice is cloned into an owned [`Vec`] once before rotating.
+    ///
+    /// # Panics
+    /// Panics if `mid` is greater than the length of the slice.
+    ///
+    /// # Example
+    /// ```
+    /// use std::rc::Rc;
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let data = [Rc::new(1), Rc::new(2), Rc::new(3)];
+    /// let mut borrowed: NoStdCow<'_, Vec<Rc<i32>>, [Rc<i32>]> = NoStdCow::Borrowed(&data);
+    /// borrowed.rotate_left(1);
+    /// assert_eq!(&*borrowed, &[Rc::new(2), Rc::new(3), Rc::new(1)]);
+    /// // Every element was cloned exactly once.
+    /// assert!(data.iter().all(|rc| Rc::strong_count(rc) == 2));
+    ///
+    /// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+    /// owned.rotate_left(2);
+    /// assert_eq!(owned, [3u8, 1, 2]);
+    /// ```
+    pub fn rotate_left(&mut self, mid: usize) {
+        self.owned_vec_mut().rotate_left(mid)
+    }
+    /// Rotates the elements in place so the last `k` elements come first,
+    /// like [`slice::rotate_right`](https://doc.rust-lang.org/core/primitive.slice.html#method.rotate_right).
+    ///
+    /// A borrowed slice is cloned into an owned [`Vec`] once before rotating.
+    ///
+    /// # Panics
+    /// Panics if `k` is greater than the length of the slice.
+    ///
+    /// # Example
+    /// ```
+    /// use std::rc::Rc;
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let data = [Rc::new(1), Rc::new(2), Rc::new(3)];
+    /// let mut borrowed: NoStdCow<'_, Vec<Rc<i32>>, [Rc<i32>]> = NoStdCow::Borrowed(&data);
+    /// borrowed.rotate_right(1);
+    /// assert_eq!(&*borrowed, &[Rc::new(3), Rc::new(1), Rc::new(2)]);
+    /// // Every element was cloned exactly once.
+    /// assert!(data.iter().all(|rc| Rc::strong_count(rc) == 2));
+    ///
+    /// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+    /// owned.rotate_right(2);
+    /// assert_eq!(owned, [2u8, 3, 1]);
+    /// ```
+    pub fn rotate_right(&mut self, k: usize) {
+        self.owned_vec_mut().rotate_right(k)
+    }
+    /// Removes and returns the element at `index`, shifting the following elements
+    /// left, like [`Vec::remove`].
+    ///
+    /// A borrowed slice is cloned into an owned [`Vec`] once before removing.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+    /// assert_eq!(borrowed.remove(0), 1);
+    /// assert_eq!(borrowed, [2u8, 3]);
+    /// assert!(borrowed.is_owned());
+    ///
+    /// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+    /// assert_eq!(owned.remove(1), 2);
+    /// assert_eq!(owned, [1u8, 3]);
+    /// ```
+    /// ```should_panic
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut cow: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+    /// cow.remove(3);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> U {
+        self.owned_vec_mut().remove(index)
+    }
+    /// Removes and returns the element at `index`, replacing it with the last element,
+    /// like [`Vec::swap_remove`].
+    ///
+    /// A borrowed slice is cloned into an owned [`Vec`] once before removing.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+    /// assert_eq!(borrowed.swap_remove(0), 1);
+    /// assert_eq!(borrowed, [3u8, 2]);
+    ///
+    /// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+    /// assert_eq!(owned.swap_remove(2), 3);
+    /// assert_eq!(owned, [1u8, 2]);
+    /// ```
+    /// ```should_panic
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut cow: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(Vec::new());
+    /// cow.swap_remove(0);
+    /// ```
+    pub fn swap_remove(&mut self, index: usize) -> U {
+        self.owned_vec_mut().swap_remove(index)
+    }
+    /// Sorts the elements in place, preserving the order of equal elements, like
+    /// [`slice::sort`](https://doc.rust-lang.org/std/primitive.slice.html#method.sort).
+    ///
+    /// A borrowed slice is cloned into an owned [`Vec`] once before sorting.
+    ///
+    /// # Example
+    /// ```
+    /// use std::rc::Rc;
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let data = [Rc::new(3), Rc::new(1), Rc::new(2)];
+    /// let mut borrowed: NoStdCow<'_, Vec<Rc<i32>>, [Rc<i32>]> = NoStdCow::Borrowed(&data);
+    /// borrowed.sort();
+    /// assert_eq!(&*borrowed, &[Rc::new(1), Rc::new(2), Rc::new(3)]);
+    /// // Every element was cloned exactly once.
+    /// assert!(data.iter().all(|rc| Rc::strong_count(rc) == 2));
+    ///
+    /// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![3, 1, 2]);
+    /// owned.sort();
+    /// assert_eq!(owned, [1u8, 2, 3]);
+    /// ```
+    pub fn sort(&mut self)
+    where
+        U: Ord,
+    {
+        self.owned_vec_mut().sort()
+    }
+    /// Sorts the elements in place without preserving the order of equal elements, like
+    /// [`slice::sort_unstable`](https://doc.rust-lang.org/core/primitive.slice.html#method.sort_unstable).
+    ///
+    /// A borrowed slice is cloned into an owned [`Vec`] once before sorting.
+    ///
+    /// # Example
+    /// ```
+    /// use std::rc::Rc;
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let data = [Rc::new(3), Rc::new(1), Rc::new(2)];
+    /// let mut borrowed: NoStdCow<'_, Vec<Rc<i32>>, [Rc<i32>]> = NoStdCow::Borrowed(&data);
+    /// borrowed.sort_unstable();
+    /// assert_eq!(&*borrowed, &[Rc::new(1), Rc::new(2), Rc::new(3)]);
+    /// // Every element was cloned exactly once.
+    /// assert!(data.iter().all(|rc| Rc::strong_count(rc) == 2));
+    ///
+    /// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![3, 1, 2]);
+    /// owned.sort_unstable();
+    /// assert_eq!(owned, [1u8, 2, 3]);
+    /// ```
+    pub fn sort_unstable(&mut self)
+    where
+        U: Ord,
+    {
+        self.owned_vec_mut().sort_unstable()
+    }
+    /// Tries to reserve capacity for at least `additional` more elements, like
+    /// [`Vec::try_reserve`].
+    ///
+    /// A borrowed slice is cloned once into a [`Vec`] whose allocation already includes
+    /// the extra capacity. Allocation failure is returned as an error instead of
+    /// aborting, and leaves the cow unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2]);
+    /// borrowed.try_reserve(8).unwrap();
+    /// assert!(borrowed.is_owned());
+    /// assert!(Vec::from(borrowed).capacity() >= 10);
+    ///
+    /// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2]);
+    /// assert!(owned.try_reserve(usize::MAX).is_err());
+    /// assert_eq!(owned, NoStdCow::Owned(vec![1, 2]));
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        match self {
+            Self::Borrowed(b) => {
+                let mut owned = Vec::new();
+                owned.try_reserve(b.len().saturating_add(additional))?;
+                owned.extend_from_slice(b);
+                *self = Self::Owned(owned);
+                Ok(())
+            }
+            Self::Owned(o) => o.try_reserve(additional),
+        }
+    }
+}
+
+impl<U> NoStdCow<'_, Vec<U>, [U]> {
+    /// Returns the approximate heap footprint of the contents in bytes.
+    ///
+    /// This is the [`Vec::capacity`] of an owned value multiplied by the element size,
+    /// and `0` for borrowed data, which lives elsewhere. Heap memory owned by the
+    /// elements themselves is not counted.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let owned: NoStdCow<'_, Vec<u32>, [u32]> = NoStdCow::Owned(Vec::with_capacity(8));
+    /// assert_eq!(owned.heap_bytes(), 32);
+    /// let borrowed: NoStdCow<'_, Vec<u32>, [u32]> = NoStdCow::Borrowed(&[1, 2, 3]);
+    /// assert_eq!(borrowed.heap_bytes(), 0);
+    /// ```
+    pub fn heap_bytes(&self) -> usize {
+        match self {
+            Self::Borrowed(_) => 0,
+            Self::Owned(o) => o.capacity() * core::mem::size_of::<U>(),
+        }
+    }
+    /// Shortens the slice to `len` elements, like [`Vec::truncate`].
+    ///
+    /// A borrowed slice is narrowed to a shorter borrow without copying, while an
+    /// owned [`Vec`] is truncated in place. Has no effect if `len` is greater than
+    /// the current length.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+    /// borrowed.truncate(2);
+    /// assert_eq!(borrowed, NoStdCow::Borrowed(&[1, 2][..]));
+    /// assert!(borrowed.is_borrowed());
+    ///
+    /// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+    /// owned.truncate(2);
+    /// assert_eq!(owned, NoStdCow::Owned(vec![1, 2]));
+    /// owned.truncate(5);
+    /// assert_eq!(owned, NoStdCow::Owned(vec![1, 2]));
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        match self {
+            Self::Borrowed(b) => {
+                if len < b.len() {
+                    *b = &b[..len];
+                }
+            }
+            Self::Owned(o) => o.truncate(len),
+        }
+    }
+}
+
+impl<U: 'static> NoStdCow<'static, Vec<U>, [U]> {
+    /// Converts the cow into a `'static` slice, leaking an owned [`Vec`].
+    ///
+    /// Borrowed data is already `'static` and is returned as is. For an owned value
+    /// the allocation is intentionally leaked with [`Vec::leak`] and will never be
+    /// freed, so only use this for data that must live for the rest of the program.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let owned: NoStdCow<'static, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+    /// let leaked: &'static [u8] = owned.leak();
+    /// assert_eq!(leaked, &[1, 2, 3]);
+    /// ```
+    pub fn leak(self) -> &'static [U] {
+        match self {
+            Self::Borrowed(b) => b,
+            Self::Owned(o) => o.leak(),
+        }
+    }
+}
+
+/// Extends the contents from an iterator of element references.
+///
+/// A borrowed slice is cloned into an owned [`Vec`] once before extending.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let mut borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2]);
+/// borrowed.extend([3u8, 4].iter());
+/// assert_eq!(borrowed, [1u8, 2, 3, 4]);
+/// assert!(borrowed.is_owned());
+///
+/// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1]);
+/// owned.extend(&[2u8, 3]);
+/// assert_eq!(owned, [1u8, 2, 3]);
+/// ```
+impl<'b, U: Copy + 'b> Extend<&'b U> for NoStdCow<'_, Vec<U>, [U]> {
+    fn extend<I: IntoIterator<Item = &'b U>>(&mut self, iter: I) {
+        self.owned_vec_mut().extend(iter)
+    }
+}
+
+/// Appends a slice with `+=`, like [`Vec::extend_from_slice`].
+///
+/// A borrowed slice is cloned into an owned [`Vec`] once before appending.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let mut borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2]);
+/// borrowed += &[3u8, 4][..];
+/// assert_eq!(borrowed, NoStdCow::Owned(vec![1, 2, 3, 4]));
+///
+/// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1]);
+/// owned += &[2u8][..];
+/// owned += &[][..];
+/// assert_eq!(owned, NoStdCow::Owned(vec![1, 2]));
+/// ```
+impl<U: Copy> AddAssign<&[U]> for NoStdCow<'_, Vec<U>, [U]> {
+    fn add_assign(&mut self, rhs: &[U]) {
+        self.owned_vec_mut().extend_from_slice(rhs)
+    }
+}
+
+/// Converts a slice cow into an owned [`Vec`], cloning the slice if it is borrowed.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+/// let owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+/// assert_eq!(Vec::from(borrowed), vec![1, 2, 3]);
+/// assert_eq!(Vec::from(owned), vec![1, 2, 3]);
+/// ```
+impl<U: Clone> From<NoStdCow<'_, Vec<U>, [U]>> for Vec<U> {
+    fn from(value: NoStdCow<'_, Vec<U>, [U]>) -> Self {
+        match value {
+            NoStdCow::Borrowed(b) => b.to_vec(),
+            NoStdCow::Owned(o) => o,
+        }
+    }
+}
+
+impl<'a> NoStdCow<'a, String, str> {
+    /// Joins the string cows with `sep` between each pair of segments.
+    ///
+    /// No separator is needed for fewer than two segments, so zero segments give
+    /// an empty [`NoStdCow::Borrowed`] and a single segment is passed through
+    /// unchanged, keeping its variant. Two or more segments are concatenated into
+    /// an owned [`String`].
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let none: Vec<NoStdCow<'_, String, str>> = Vec::new();
+    /// assert_eq!(NoStdCow::join(none, ", "), NoStdCow::Borrowed(""));
+    ///
+    /// let one = [NoStdCow::Borrowed("a")];
+    /// let joined = NoStdCow::join(one, ", ");
+    /// assert_eq!(joined, NoStdCow::Borrowed("a"));
+    /// assert!(joined.is_borrowed());
+    ///
+    /// let many = [NoStdCow::Borrowed("a"), NoStdCow::Owned(String::from("b")), NoStdCow::Borrowed("c")];
+    /// let joined = NoStdCow::join(many, ", ");
+    /// assert_eq!(joined, NoStdCow::Owned(String::from("a, b, c")));
+    /// assert!(joined.is_owned());
+    /// ```
+    pub fn join<I: IntoIterator<Item = NoStdCow<'a, String, str>>>(parts: I, sep: &str) -> Self {
+        let mut parts = parts.into_iter();
+        let Some(first) = parts.next() else {
+            return Self::Borrowed("");
+        };
+        let Some(second) = parts.next() else {
+            return first;
+        };
+        let mut joined = first.into_string();
+        for part in core::iter::once(second).chain(parts) {
+            joined.push_str(sep);
+            joined.push_str(&part);
+        }
+        Self::Owned(joined)
+    }
+    /// Converts bytes to a string cow, replacing invalid UTF-8 sequences with
+    /// `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// Mirrors [`String::from_utf8_lossy`]: valid UTF-8 is returned as a zero-copy
+    /// [`NoStdCow::Borrowed`], and only invalid input allocates an owned replacement.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let valid = NoStdCow::from_utf8_lossy(b"hello");
+    /// assert_eq!(valid, NoStdCow::Borrowed("hello"));
+    /// assert!(valid.is_borrowed());
+    ///
+    /// let invalid = NoStdCow::from_utf8_lossy(b"hi\xFFthere");
+    /// assert_eq!(invalid, NoStdCow::Owned(String::from("hi\u{FFFD}there")));
+    /// assert!(invalid.is_owned());
+    /// ```
+    pub fn from_utf8_lossy(bytes: &'a [u8]) -> Self {
+        Self::from_alloc_cow(String::from_utf8_lossy(bytes))
+    }
+    /// Returns the approximate heap footprint of the contents in bytes.
+    ///
+    /// This is the [`String::capacity`] of an owned value and `0` for borrowed data,
+    /// which lives elsewhere.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::with_capacity(16));
+    /// assert_eq!(owned.heap_bytes(), 16);
+    /// let borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("hello");
+    /// assert_eq!(borrowed.heap_bytes(), 0);
+    /// ```
+    pub fn heap_bytes(&self) -> usize {
+        match self {
+            Self::Borrowed(_) => 0,
+            Self::Owned(o) => o.capacity(),
+        }
+    }
+    /// Shortens the string to `len` bytes, like [`String::truncate`].
+    ///
+    /// A borrowed string is narrowed to a shorter borrow without copying, while an
+    /// owned [`String`] is truncated in place. Has no effect if `len` is greater than
+    /// the current length.
+    ///
+    /// # Panics
+    /// Panics if `len` does not lie on a [`char`] boundary.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("hello");
+    /// borrowed.truncate(2);
+    /// assert_eq!(borrowed, NoStdCow::Borrowed("he"));
+    /// assert!(borrowed.is_borrowed());
+    ///
+    /// let mut owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("hello"));
+    /// owned.truncate(2);
+    /// assert_eq!(owned, NoStdCow::Owned(String::from("he")));
+    /// ```
+    /// ```should_panic
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut cow: NoStdCow<'_, String, str> = NoStdCow::Borrowed("héllo");
+    /// // Byte 2 is inside 'é'.
+    /// cow.truncate(2);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        match self {
+            Self::Borrowed(b) => {
+                if len < b.len() {
+                    assert!(b.is_char_boundary(len));
+                    *b = &b[..len];
+                }
+            }
+            Self::Owned(o) => o.truncate(len),
+        }
+    }
+    /// Tries to reserve capacity for at least `additional` more bytes, like
+    /// [`String::try_reserve`].
+    ///
+    /// A borrowed string is copied once into a [`String`] whose allocation already
+    /// includes the extra capacity. Allocation failure is returned as an error instead
+    /// of aborting, and leaves the cow unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("hi");
+    /// borrowed.try_reserve(8).unwrap();
+    /// assert!(borrowed.is_owned());
+    /// assert!(borrowed.heap_bytes() >= 10);
+    ///
+    /// let mut owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("hi"));
+    /// assert!(owned.try_reserve(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        match self {
+            Self::Borrowed(b) => {
+                let mut owned = String::new();
+                owned.try_reserve(b.len().saturating_add(additional))?;
+                owned.push_str(b);
+                *self = Self::Owned(owned);
+                Ok(())
+            }
+            Self::Owned(o) => o.try_reserve(additional),
+        }
+    }
+    /// Converts the cow into one that is guaranteed to be [`NoStdCow::Owned`].
+    ///
+    /// Copies the string if it is borrowed. Since the result no longer borrows
+    /// anything it can have any lifetime, including `'static`.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("hello");
+    /// let owned: NoStdCow<'static, String, str> = borrowed.into_owned_cow();
+    /// assert_eq!(owned, NoStdCow::Owned(String::from("hello")));
+    /// ```
+    pub fn into_owned_cow<'b>(self) -> NoStdCow<'b, String, str> {
+        NoStdCow::Owned(self.into_string())
+    }
+    /// Acquires a mutable reference to the string, copying it into an owned
+    /// [`String`] if it is borrowed.
+    ///
+    /// Unlike `to_mut`, which needs `B == T`, this returns the unsized `&mut str`
+    /// through the [`DerefMut`](core::ops::DerefMut) of the owned [`String`].
+    /// Requires the `alloc` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("hello");
+    /// borrowed.to_mut_b().make_ascii_uppercase();
+    /// assert_eq!(borrowed, NoStdCow::Owned(String::from("HELLO")));
+    ///
+    /// let mut owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("HeLLo"));
+    /// owned.to_mut_b().make_ascii_lowercase();
+    /// assert_eq!(owned, NoStdCow::Owned(String::from("hello")));
+    /// ```
+    pub fn to_mut_b(&mut self) -> &mut str {
+        if let Self::Borrowed(b) = *self {
+            *self = Self::Owned(String::from(b));
+        }
+        match self {
+            Self::Borrowed(_) => unreachable!(),
+            Self::Owned(o) => o,
+        }
+    }
+    /// Returns the string escaped with [`str::escape_default`], borrowing when nothing
+    /// needs escaping.
+    ///
+    /// If every character escapes to itself, which is the case for printable ASCII
+    /// other than `\`, `'` and `"`, a [`NoStdCow::Borrowed`] view of the contents is
+    /// returned without allocating. Otherwise the escaped string is collected into an
+    /// owned [`String`].
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let clean: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("plain text"));
+    /// assert_eq!(clean.escape_default_cow(), NoStdCow::Borrowed("plain text"));
+    /// assert!(clean.escape_default_cow().is_borrowed());
+    ///
+    /// let control: NoStdCow<'_, String, str> = NoStdCow::Borrowed("line\n\tend\u{7}");
+    /// let escaped = control.escape_default_cow();
+    /// assert_eq!(escaped, NoStdCow::Borrowed(r"line\n\tend\u{7}"));
+    /// assert!(escaped.is_owned());
+    /// ```
+    pub fn escape_default_cow(&self) -> NoStdCow<'_, String, str> {
+        let s: &str = self;
+        if s.chars().all(|c| c.escape_default().len() == 1) {
+            return NoStdCow::Borrowed(s);
+        }
+        NoStdCow::Owned(s.escape_default().collect())
+    }
+    /// Extracts the owned [`String`], copying the contents if they are borrowed.
+    fn into_string(self) -> String {
+        match self {
+            Self::Borrowed(b) => String::from(b),
+            Self::Owned(o) => o,
+        }
+    }
+}
+
+/// Validates a byte cow as UTF-8 and converts it into a str cow, keeping the
+/// variant.
+///
+/// A borrowed byte slice becomes a borrowed `str` without copying, and an owned
+/// [`Vec`] is reused as the [`String`] buffer. Invalid UTF-8 is reported with
+/// [`Utf8Error`].
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(b"hello");
+/// let text = NoStdCow::<'_, String, str>::try_from(borrowed).unwrap();
+/// assert_eq!(text, NoStdCow::Borrowed("hello"));
+/// assert!(text.is_borrowed());
+///
+/// let owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(b"hello".to_vec());
+/// let ptr = owned.as_ptr();
+/// let text = NoStdCow::<'_, String, str>::try_from(owned).unwrap();
+/// assert!(text.is_owned());
+/// assert_eq!(text.as_ptr(), ptr);
+///
+/// let invalid: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(b"hi\xFF".to_vec());
+/// assert_eq!(NoStdCow::<'_, String, str>::try_from(invalid).unwrap_err().valid_up_to(), 2);
+/// ```
+impl<'a> TryFrom<NoStdCow<'a, Vec<u8>, [u8]>> for NoStdCow<'a, String, str> {
+    type Error = Utf8Error;
+    fn try_from(value: NoStdCow<'a, Vec<u8>, [u8]>) -> Result<Self, Self::Error> {
+        match value {
+            NoStdCow::Borrowed(b) => core::str::from_utf8(b).map(NoStdCow::Borrowed),
+            NoStdCow::Owned(o) => String::from_utf8(o).map(NoStdCow::Owned).map_err(|e| e.utf8_error()),
+        }
+    }
+}
+
+/// Converts a string cow into a [`Box<str>`], copying the string if it is borrowed.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("hello");
+/// let owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("hello"));
+/// assert_eq!(&*Box::<str>::from(borrowed), "hello");
+/// assert_eq!(&*Box::<str>::from(owned), "hello");
+/// ```
+impl From<NoStdCow<'_, String, str>> for Box<str> {
+    fn from(value: NoStdCow<'_, String, str>) -> Self {
+        match value {
+            NoStdCow::Borrowed(b) => Box::from(b),
+            NoStdCow::Owned(o) => o.into_boxed_str(),
+        }
+    }
+}
+
+/// Converts a slice cow into a [`Box<[U]>`](Box), cloning the slice if it is borrowed.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+/// let owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+/// assert_eq!(&*Box::<[u8]>::from(borrowed), &[1, 2, 3]);
+/// assert_eq!(&*Box::<[u8]>::from(owned), &[1, 2, 3]);
+/// ```
+impl<U: Clone> From<NoStdCow<'_, Vec<U>, [U]>> for Box<[U]> {
+    fn from(value: NoStdCow<'_, Vec<U>, [U]>) -> Self {
+        Vec::from(value).into_boxed_slice()
+    }
+}
+
+/// Wraps a boxed value as an owned cow, which is how unsized `B` such as trait
+/// objects are held.
+///
+/// A `NoStdCow<'_, Box<dyn Trait>, dyn Trait>` holds either a borrowed `&dyn Trait`
+/// or an owned [`Box<dyn Trait>`](Box), and dereferences to `dyn Trait` in both
+/// cases. The borrowed side works without `alloc`, but boxing the owned side needs
+/// the `alloc` feature.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// trait Shape {
+///     fn area(&self) -> u32;
+/// }
+/// struct Square(u32);
+/// impl Shape for Square {
+///     fn area(&self) -> u32 {
+///         self.0 * self.0
+///     }
+/// }
+///
+/// let square = Square(3);
+/// let borrowed: NoStdCow<'_, Box<dyn Shape>, dyn Shape> = NoStdCow::Borrowed(&square);
+/// let owned: NoStdCow<'_, Box<dyn Shape>, dyn Shape> = NoStdCow::from(Box::new(Square(2)) as Box<dyn Shape>);
+/// assert_eq!(borrowed.area(), 9);
+/// assert_eq!(owned.area(), 4);
+/// assert!(owned.is_owned());
+/// ```
+impl<B: ?Sized> From<Box<B>> for NoStdCow<'_, Box<B>, B> {
+    fn from(value: Box<B>) -> Self {
+        Self::Owned(value)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::NoStdCow;
+use core::borrow::Borrow;
+use core::ops::Deref;
+
+/// A fluent wrapper for applying transformations to a cow only when they're needed.
+///
+/// Each [`CowBuilder::map_if`] step whose condition is false is a no-op, so a
+/// chain where nothing applies hands back the original cow, still borrowed if it
+/// started that way. An owned value is only built when a transformation runs.
+///
+/// # Example
+/// ```
+/// use nostd_cow::{CowBuilder, NoStdCow};
+///
+/// fn normalize(source: &str) -> NoStdCow<'_, String, str> {
+///     CowBuilder::new(NoStdCow::Borrowed(source))
+///         .map_if(source.starts_with(' ') || source.ends_with(' '), |s| String::from(s.trim()))
+///         .map_if(source.chars().any(char::is_lowercase), str::to_uppercase)
+///         .build()
+/// }
+///
+/// let clean = normalize("HELLO");
+/// assert_eq!(clean, NoStdCow::Borrowed("HELLO"));
+/// assert!(clean.is_borrowed());
+///
+/// let lower = normalize("hello");
+/// assert_eq!(lower, NoStdCow::Owned(String::from("HELLO")));
+/// assert!(lower.is_owned());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CowBuilder<'a, T: Borrow<B>, B: ?Sized> {
+    cow: NoStdCow<'a, T, B>,
+}
+
+impl<'a, T: Borrow<B>, B: ?Sized> CowBuilder<'a, T, B> {
+    /// Starts a chain of transformations from `cow`.
+    pub const fn new(cow: NoStdCow<'a, T, B>) -> Self {
+        Self { cow }
+    }
+    /// Replaces the contents with the owned result of `f` if `cond` is true.
+    ///
+    /// `f` receives the current contents, which may be the result of an earlier
+    /// step. When `cond` is false, `f` is not called and nothing changes.
+    pub fn map_if<F: FnOnce(&B) -> T>(self, cond: bool, f: F) -> Self {
+        if !cond {
+            return self;
+        }
+        Self { cow: NoStdCow::Owned(f(self.cow.deref())) }
+    }
+    /// Finishes the chain and returns the resulting cow.
+    pub fn build(self) -> NoStdCow<'a, T, B> {
+        self.cow
+    }
+}
+
+impl<'a, T: Borrow<B>, B: ?Sized> From<NoStdCow<'a, T, B>> for CowBuilder<'a, T, B> {
+    fn from(value: NoStdCow<'a, T, B>) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::NoStdCow;
+use core::borrow::Borrow;
+use core::ops::Deref;
+
+/// Memoizes the last result of a cow-producing function, keyed by its input.
+///
+/// Repeated calls with an equal input return a borrowed view of the cached result
+/// instead of recomputing, and don't construct a new owned value. Only the most
+/// recent input and result are kept.
+///
+/// # Example
+/// ```
+/// use nostd_cow::{CowCache, NoStdCow};
+///
+/// fn to_uppercase(source: &str) -> NoStdCow<'_, String, str> {
+///     if source.chars().all(char::is_uppercase) {
+///         NoStdCow::Borrowed(source)
+///     } else {
+///         NoStdCow::Owned(source.to_uppercase())
+///     }
+/// }
+///
+/// let mut cache = CowCache::new();
+/// let mut computed = 0;
+/// let first = cache.get_or_compute("hello", |s| { computed += 1; to_uppercase(s) }).as_ptr();
+/// let hit = cache.get_or_compute("hello", |s| { computed += 1; to_uppercase(s) });
+/// assert_eq!(hit, NoStdCow::Borrowed("HELLO"));
+/// assert!(hit.is_borrowed());
+/// assert_eq!(hit.as_ptr(), first);
+/// assert_eq!(computed, 1);
+///
+/// let miss = cache.get_or_compute("world", |s| { computed += 1; to_uppercase(s) });
+/// assert_eq!(miss, NoStdCow::Borrowed("WORLD"));
+/// assert_eq!(computed, 2);
+/// ```
+#[derive(Debug)]
+pub struct CowCache<'a, T: Borrow<B>, B: ?Sized> {
+    entry: Option<(&'a B, NoStdCow<'a, T, B>)>,
+}
+
+impl<'a, T: Borrow<B>, B: ?Sized> CowCache<'a, T, B> {
+    /// Creates an empty cache.
+    pub const fn new() -> Self {
+        Self { entry: None }
+    }
+    /// Returns a borrowed view of the result for `key`, running `compute` only if
+    /// `key` differs from the previously cached key.
+    ///
+    /// On a miss the previous result is dropped and replaced by the new one.
+    pub fn get_or_compute<F>(&mut self, key: &'a B, compute: F) -> NoStdCow<'_, T, B>
+    where
+        B: PartialEq,
+        F: FnOnce(&'a B) -> NoStdCow<'a, T, B>,
+    {
+        if !self.contains_key(key) {
+            self.entry = None;
+        }
+        let (_, value) = &*self.entry.get_or_insert_with(|| (key, compute(key)));
+        NoStdCow::Borrowed(value.deref())
+    }
+    /// Returns true if `key` is the currently cached key.
+    pub fn contains_key(&self, key: &B) -> bool
+    where
+        B: PartialEq,
+    {
+        self.entry.as_ref().is_some_and(|(cached, _)| *cached == key)
+    }
+    /// Drops the cached result, if any.
+    pub fn clear(&mut self) {
+        self.entry = None;
+    }
+}
+
+impl<T: Borrow<B>, B: ?Sized> Default for CowCache<'_, T, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
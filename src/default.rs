@@ -0,0 +1,76 @@
+use crate::NoStdCow;
+use core::borrow::Borrow;
+
+/// A strategy for building a default cow, selected with [`NoStdCow::default_with`].
+///
+/// The [`Default`] implementation of [`NoStdCow`] always produces an owned
+/// `T::default()`, which may allocate. Choose [`DefaultBorrowed`] instead to get a
+/// borrowed `'static` empty value, or implement this trait on your own marker type
+/// for other strategies.
+///
+/// # Example
+/// ```
+/// use nostd_cow::{DefaultBorrowed, DefaultOwned, NoStdCow};
+///
+/// let owned: NoStdCow<'_, String, str> = NoStdCow::default_with::<DefaultOwned>();
+/// assert_eq!(owned, NoStdCow::Owned(String::new()));
+/// let borrowed: NoStdCow<'_, String, str> = NoStdCow::default_with::<DefaultBorrowed>();
+/// assert_eq!(borrowed, NoStdCow::Borrowed(""));
+/// ```
+pub trait DefaultStrategy<T: Borrow<B>, B: ?Sized> {
+    /// Builds the default cow.
+    fn default_cow<'a>() -> NoStdCow<'a, T, B>;
+}
+
+/// A [`DefaultStrategy`] producing [`NoStdCow::Owned`] with `T::default()`, the same
+/// as the [`Default`] implementation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefaultOwned;
+
+/// A [`DefaultStrategy`] producing [`NoStdCow::Borrowed`] with a `'static` empty
+/// value, so it never allocates.
+///
+/// This is implemented for `str` and slices.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefaultBorrowed;
+
+impl<T: Borrow<B> + Default, B: ?Sized> DefaultStrategy<T, B> for DefaultOwned {
+    fn default_cow<'a>() -> NoStdCow<'a, T, B> {
+        NoStdCow::Owned(T::default())
+    }
+}
+
+impl<T: Borrow<str>> DefaultStrategy<T, str> for DefaultBorrowed {
+    fn default_cow<'a>() -> NoStdCow<'a, T, str> {
+        NoStdCow::Borrowed("")
+    }
+}
+
+impl<U, T: Borrow<[U]>> DefaultStrategy<T, [U]> for DefaultBorrowed {
+    fn default_cow<'a>() -> NoStdCow<'a, T, [U]> {
+        NoStdCow::Borrowed(&[])
+    }
+}
+
+impl<T: Borrow<B>, B: ?Sized> NoStdCow<'_, T, B> {
+    /// Builds a default cow using the strategy `S`.
+    ///
+    /// [`Default::default`] is equivalent to `default_with::<DefaultOwned>()`, while
+    /// [`DefaultBorrowed`] gives `no_std` users a default that never allocates.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::{DefaultBorrowed, DefaultOwned, NoStdCow};
+    ///
+    /// let owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::default_with::<DefaultOwned>();
+    /// assert!(owned.is_owned());
+    /// assert_eq!(owned, NoStdCow::default());
+    ///
+    /// let borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::default_with::<DefaultBorrowed>();
+    /// assert!(borrowed.is_borrowed());
+    /// assert!(borrowed.is_empty());
+    /// ```
+    pub fn default_with<S: DefaultStrategy<T, B>>() -> Self {
+        S::default_cow()
+    }
+}
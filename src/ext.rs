@@ -0,0 +1,61 @@
+use crate::RefCow;
+
+/// Extension methods for working with cows wrapped in [`Option`] or [`Result`]
+/// without manual matching.
+///
+/// # Example
+/// ```
+/// use nostd_cow::{CowExt, RefCow};
+///
+/// let value = 5;
+/// let some: Option<RefCow<'_, i32>> = Some(RefCow::Borrowed(&value));
+/// let none: Option<RefCow<'_, i32>> = None;
+/// assert!(some.is_borrowed_cow());
+/// assert!(!none.is_borrowed_cow());
+/// assert_eq!(some.into_owned_opt(), Some(5));
+/// assert_eq!(none.into_owned_opt(), None);
+///
+/// let ok: Result<RefCow<'_, i32>, &str> = Ok(RefCow::Owned(5));
+/// let err: Result<RefCow<'_, i32>, &str> = Err("failed");
+/// assert!(ok.is_owned_cow());
+/// assert!(!err.is_owned_cow());
+/// assert_eq!(ok.into_owned_opt(), Some(5));
+/// assert_eq!(err.into_owned_opt(), None);
+/// ```
+pub trait CowExt {
+    /// The owned type of the wrapped cow.
+    type Owned;
+    /// Extracts the owned data of the wrapped cow if there is one, cloning it if it
+    /// is borrowed.
+    fn into_owned_opt(self) -> Option<Self::Owned>;
+    /// Returns true if there is a wrapped cow and it is borrowed.
+    fn is_borrowed_cow(&self) -> bool;
+    /// Returns true if there is a wrapped cow and it is owned.
+    fn is_owned_cow(&self) -> bool;
+}
+
+impl<T: Clone> CowExt for Option<RefCow<'_, T>> {
+    type Owned = T;
+    fn into_owned_opt(self) -> Option<T> {
+        self.map(RefCow::into_owned)
+    }
+    fn is_borrowed_cow(&self) -> bool {
+        self.as_ref().is_some_and(RefCow::is_borrowed)
+    }
+    fn is_owned_cow(&self) -> bool {
+        self.as_ref().is_some_and(RefCow::is_owned)
+    }
+}
+
+impl<T: Clone, E> CowExt for Result<RefCow<'_, T>, E> {
+    type Owned = T;
+    fn into_owned_opt(self) -> Option<T> {
+        self.ok().map(RefCow::into_owned)
+    }
+    fn is_borrowed_cow(&self) -> bool {
+        self.as_ref().is_ok_and(RefCow::is_borrowed)
+    }
+    fn is_owned_cow(&self) -> bool {
+        self.as_ref().is_ok_and(RefCow::is_owned)
+    }
+}
@@ -0,0 +1,169 @@
+use crate::NoStdCow;
+use core::borrow::Borrow;
+use core::ops::Deref;
+use heapless::CapacityError;
+
+impl<U: Clone, T: Borrow<[U]>> NoStdCow<'_, T, [U]> {
+    /// Copy up to `N` elements into a [`heapless::Vec`], silently dropping the rest.
+    ///
+    /// Unlike a fallible conversion, this never errors: if the contents are longer
+    /// than `N`, only the first `N` elements are kept. This is intended for best-effort
+    /// scenarios such as logging where losing the tail is acceptable.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let exact: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+    /// assert_eq!(exact.into_owned_truncated::<3>().as_slice(), &[1, 2, 3]);
+    ///
+    /// let under: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2]);
+    /// assert_eq!(under.into_owned_truncated::<3>().as_slice(), &[1, 2]);
+    ///
+    /// let over: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3, 4]);
+    /// assert_eq!(over.into_owned_truncated::<3>().as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn into_owned_truncated<const N: usize>(self) -> heapless::Vec<U, N> {
+        let src = self.deref();
+        let mut out = heapless::Vec::new();
+        // Cannot fail, the slice has already been truncated to the capacity.
+        let _ = out.extend_from_slice(&src[..src.len().min(N)]);
+        out
+    }
+}
+
+impl<T: Borrow<str>> NoStdCow<'_, T, str> {
+    /// Copy up to `N` bytes into a [`heapless::String`], silently dropping the rest.
+    ///
+    /// Unlike a fallible conversion, this never errors: if the contents are longer
+    /// than `N` bytes, the string is cut at the last char boundary that fits, so the
+    /// result may hold fewer than `N` bytes when a multi-byte character straddles
+    /// the limit. This is intended for best-effort scenarios such as logging.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let exact: NoStdCow<'_, String, str> = NoStdCow::Borrowed("abc");
+    /// assert_eq!(exact.into_owned_truncated::<3>().as_str(), "abc");
+    ///
+    /// let under: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("ab"));
+    /// assert_eq!(under.into_owned_truncated::<3>().as_str(), "ab");
+    ///
+    /// let over: NoStdCow<'_, String, str> = NoStdCow::Borrowed("abcd");
+    /// assert_eq!(over.into_owned_truncated::<3>().as_str(), "abc");
+    ///
+    /// // 'é' takes two bytes and doesn't fit after "ab".
+    /// let multi_byte: NoStdCow<'_, String, str> = NoStdCow::Borrowed("abé");
+    /// assert_eq!(multi_byte.into_owned_truncated::<3>().as_str(), "ab");
+    /// ```
+    pub fn into_owned_truncated<const N: usize>(self) -> heapless::String<N> {
+        let src = self.deref();
+        let mut end = src.len().min(N);
+        while !src.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut out = heapless::String::new();
+        // Cannot fail, the string has already been truncated to the capacity.
+        let _ = out.push_str(&src[..end]);
+        out
+    }
+}
+
+impl<U: Clone, const N: usize> NoStdCow<'_, heapless::Vec<U, N>, [U]> {
+    /// Checks that there is room for `additional` more elements, cloning a borrowed
+    /// slice into a [`heapless::Vec`] once.
+    ///
+    /// A [`heapless::Vec`] has a fixed capacity of `N`, so nothing is allocated. If the
+    /// contents plus `additional` would exceed `N`, an error is returned and the cow
+    /// is left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut cow: NoStdCow<'_, heapless::Vec<u8, 4>, [u8]> = NoStdCow::Borrowed(&[1, 2]);
+    /// cow.try_reserve(2).unwrap();
+    /// assert!(cow.is_owned());
+    ///
+    /// let mut full: NoStdCow<'_, heapless::Vec<u8, 4>, [u8]> = NoStdCow::Borrowed(&[1, 2]);
+    /// assert!(full.try_reserve(3).is_err());
+    /// assert!(full.is_borrowed());
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), CapacityError> {
+        if self.len().saturating_add(additional) > N {
+            return Err(CapacityError::default());
+        }
+        if let Self::Borrowed(b) = *self {
+            *self = Self::Owned(heapless::Vec::from_slice(b)?);
+        }
+        Ok(())
+    }
+    /// Tries to append an element to the end, cloning a borrowed slice into a
+    /// [`heapless::Vec`] once.
+    ///
+    /// If there is no room left in the fixed capacity `N`, `value` is handed back in
+    /// the error and the cow is left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut cow: NoStdCow<'_, heapless::Vec<u8, 3>, [u8]> = NoStdCow::Borrowed(&[1, 2]);
+    /// assert_eq!(cow.try_push(3), Ok(()));
+    /// assert_eq!(cow, [1u8, 2, 3]);
+    /// assert!(cow.is_owned());
+    /// assert_eq!(cow.try_push(4), Err(4));
+    ///
+    /// let mut full: NoStdCow<'_, heapless::Vec<u8, 2>, [u8]> = NoStdCow::Borrowed(&[1, 2]);
+    /// assert_eq!(full.try_push(3), Err(3));
+    /// assert!(full.is_borrowed());
+    /// ```
+    pub fn try_push(&mut self, value: U) -> Result<(), U> {
+        if self.len() >= N {
+            return Err(value);
+        }
+        if let Self::Borrowed(b) = *self {
+            match heapless::Vec::from_slice(b) {
+                Ok(owned) => *self = Self::Owned(owned),
+                Err(_) => return Err(value),
+            }
+        }
+        match self {
+            Self::Borrowed(_) => unreachable!(),
+            Self::Owned(o) => o.push(value),
+        }
+    }
+}
+
+impl<const N: usize> NoStdCow<'_, heapless::String<N>, str> {
+    /// Checks that there is room for `additional` more bytes, copying a borrowed
+    /// string into a [`heapless::String`] once.
+    ///
+    /// A [`heapless::String`] has a fixed capacity of `N` bytes, so nothing is
+    /// allocated. If the contents plus `additional` would exceed `N`, an error is
+    /// returned and the cow is left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut cow: NoStdCow<'_, heapless::String<4>, str> = NoStdCow::Borrowed("hi");
+    /// cow.try_reserve(2).unwrap();
+    /// assert!(cow.is_owned());
+    ///
+    /// let mut full: NoStdCow<'_, heapless::String<4>, str> = NoStdCow::Borrowed("hi");
+    /// assert!(full.try_reserve(3).is_err());
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), CapacityError> {
+        if self.len().saturating_add(additional) > N {
+            return Err(CapacityError::default());
+        }
+        if let Self::Borrowed(b) = *self {
+            let mut owned = heapless::String::new();
+            owned.push_str(b)?;
+            *self = Self::Owned(owned);
+        }
+        Ok(())
+    }
+}
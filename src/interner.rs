@@ -0,0 +1,81 @@
+use crate::NoStdCow;
+use core::borrow::Borrow;
+use core::cell::OnceCell;
+use core::marker::PhantomData;
+
+/// A fixed-capacity pool that deduplicates values and hands out borrowed cows into
+/// caller-supplied storage.
+///
+/// Each slot of the storage holds at most one value, so no allocation happens. The
+/// returned cows borrow from the storage rather than from the interner, so several
+/// of them can be held at once, for example in a `no_std` symbol table.
+///
+/// # Example
+/// ```
+/// use core::cell::OnceCell;
+/// use nostd_cow::{Interner, NoStdCow};
+///
+/// let storage: [OnceCell<String>; 2] = Default::default();
+/// let interner: Interner<'_, String, str> = Interner::new(&storage);
+///
+/// let first = interner.intern(String::from("foo"));
+/// let again = interner.intern(String::from("foo"));
+/// assert!(first.is_borrowed() && again.is_borrowed());
+/// assert_eq!(first.as_ptr(), again.as_ptr());
+///
+/// let bar = interner.intern(String::from("bar"));
+/// assert!(bar.is_borrowed());
+///
+/// // The pool is full, so new values are handed back as owned cows.
+/// let baz = interner.intern(String::from("baz"));
+/// assert_eq!(baz, NoStdCow::Owned(String::from("baz")));
+/// // Values already in the pool are still deduplicated.
+/// assert_eq!(interner.intern(String::from("bar")).as_ptr(), bar.as_ptr());
+///
+/// // Interning only needs a shared reference.
+/// fn symbol<'a>(interner: &Interner<'a, String, str>, name: &str) -> NoStdCow<'a, String, str> {
+///     interner.intern(String::from(name))
+/// }
+/// assert_eq!(symbol(&interner, "foo").as_ptr(), first.as_ptr());
+/// ```
+#[derive(Debug)]
+pub struct Interner<'a, T: Borrow<B>, B: ?Sized> {
+    slots: &'a [OnceCell<T>],
+    borrowed: PhantomData<fn() -> &'a B>,
+}
+
+impl<'a, T: Borrow<B>, B: ?Sized> Interner<'a, T, B> {
+    /// Creates an interner that stores values in `slots`.
+    ///
+    /// Slots that are already filled are treated as interned values.
+    pub const fn new(slots: &'a [OnceCell<T>]) -> Self {
+        Self { slots, borrowed: PhantomData }
+    }
+    /// Returns a borrowed cow of the pooled value equal to `value`, inserting
+    /// `value` into the first free slot if there is no such value yet.
+    ///
+    /// If the value isn't pooled and there is no free slot left, `value` is
+    /// returned as a [`NoStdCow::Owned`] instead.
+    pub fn intern(&self, value: T) -> NoStdCow<'a, T, B>
+    where
+        B: PartialEq,
+    {
+        let slots: &'a [OnceCell<T>] = self.slots;
+        let wanted: &B = value.borrow();
+        if let Some(pooled) = slots.iter().filter_map(OnceCell::get).map(T::borrow).find(|pooled| *pooled == wanted) {
+            return NoStdCow::Borrowed(pooled);
+        }
+        match slots.iter().find(|slot| slot.get().is_none()) {
+            Some(slot) => NoStdCow::Borrowed(slot.get_or_init(|| value).borrow()),
+            None => NoStdCow::Owned(value),
+        }
+    }
+    /// Returns the number of values in the pool.
+    pub fn len(&self) -> usize {
+        self.slots.iter().filter(|slot| slot.get().is_some()).count()
+    }
+    /// Returns true if no values have been pooled.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,80 @@
+use crate::NoStdCow;
+use core::borrow::Borrow;
+use core::ops::Deref;
+
+/// Backing types with a notion of length, enabling [`NoStdCow::len`] and
+/// [`NoStdCow::is_empty`].
+///
+/// This is implemented for `str` (length in bytes) and slices. Implement it for your
+/// own `B` to opt it into length queries.
+///
+/// # Example
+/// ```
+/// use nostd_cow::{CowLen, RefCow};
+///
+/// #[derive(Clone)]
+/// struct Packet {
+///     payload: [u8; 8],
+///     used: usize,
+/// }
+/// impl CowLen for Packet {
+///     fn cow_len(&self) -> usize {
+///         self.used
+///     }
+/// }
+///
+/// let packet = Packet { payload: [0; 8], used: 3 };
+/// assert_eq!(RefCow::Borrowed(&packet).len(), 3);
+/// assert!(RefCow::Owned(Packet { used: 0, ..packet }).is_empty());
+/// ```
+pub trait CowLen {
+    /// Returns the length of the value.
+    fn cow_len(&self) -> usize;
+    /// Returns true if the value has a length of zero.
+    fn cow_is_empty(&self) -> bool {
+        self.cow_len() == 0
+    }
+}
+
+impl CowLen for str {
+    fn cow_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<U> CowLen for [U] {
+    fn cow_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T: Borrow<B>, B: ?Sized + CowLen> NoStdCow<'_, T, B> {
+    /// Returns the length of the contents, as defined by [`CowLen`].
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let text: NoStdCow<'_, String, str> = NoStdCow::Borrowed("héllo");
+    /// assert_eq!(text.len(), 6);
+    /// let bytes: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+    /// assert_eq!(bytes.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.deref().cow_len()
+    }
+    /// Returns true if the contents have a length of zero, as defined by [`CowLen`].
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let text: NoStdCow<'_, String, str> = NoStdCow::Owned(String::new());
+    /// assert!(text.is_empty());
+    /// let bytes: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1]);
+    /// assert!(!bytes.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.deref().cow_is_empty()
+    }
+}
@@ -23,6 +23,7 @@
 //! // This string is already uppercase, so the function will not allocate a new [`String`].
 //! let already_uppercase = "HELLOWORLD";
 //! assert_eq!(to_uppercase(already_uppercase), NoStdCow::Borrowed(already_uppercase));
+//! assert!(to_uppercase(already_uppercase).is_borrowed());
 //! // This string needs to be converted to uppercase, so a new owned value is constructed
 //! // and returned.
 //! let not_uppercase = "helloworld";
@@ -39,8 +40,36 @@ extern crate alloc;
 #[cfg(feature = "alloc")]
 #[doc(hidden)]
 mod alloc_impls;
+mod builder;
+mod cache;
+mod default;
+mod ext;
+#[cfg(feature = "heapless")]
+#[doc(hidden)]
+mod heapless_impls;
+mod interner;
+mod len;
+mod ops_impls;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+mod serde_impls;
+mod slice_impls;
+mod str_impls;
+#[cfg(feature = "subtle")]
+#[doc(hidden)]
+mod subtle_impls;
+mod tokens;
+
+pub use builder::CowBuilder;
+pub use cache::CowCache;
+pub use default::{DefaultBorrowed, DefaultOwned, DefaultStrategy};
+pub use ext::CowExt;
+pub use interner::Interner;
+pub use len::CowLen;
+pub use ops_impls::CowCheckedAdd;
+pub use tokens::PeekableTokens;
 
-use core::{borrow::Borrow, ops::Deref};
+use core::{any::Any, borrow::Borrow, cmp::Ordering, fmt, hash::{Hash, Hasher}, ops::Deref};
 
 /// A type alias of [`NoStdCow`] that can either store `T` or `&T`. If `T` is [`Clone`],
 /// `to_mut` and `into_owned` will be available.
@@ -59,6 +88,11 @@ pub type RefCow<'a, T> = NoStdCow<'a, T, T>;
 /// methods directly on the data it encloses. If mutation is desired, `to_mut` will
 /// obtain a mutable reference to an owned value, cloning if necessary.
 /// 
+/// Equality, ordering and hashing look only at the borrowed contents, as with `Cow`,
+/// so `NoStdCow::Borrowed("x") == NoStdCow::Owned(String::from("x"))`. Earlier
+/// releases derived these traits and also compared the variant. Use
+/// [`NoStdCow::is_borrowed`] or [`NoStdCow::is_owned`] when the variant matters.
+/// 
 /// You should consider just using [`alloc::borrow::Cow`](https://doc.rust-lang.org/alloc/borrow/enum.Cow.html)
 /// from `alloc` if you have access to an allocator. [`NoStdCow::into_alloc_cow`] and
 /// [`NoStdCow::from_alloc_cow`]. [`From`] implementations are also available in
@@ -81,12 +115,36 @@ pub type RefCow<'a, T> = NoStdCow<'a, T, T>;
 /// // This string is already uppercase, so the function will not allocate a new [`String`].
 /// let already_uppercase = "HELLOWORLD";
 /// assert_eq!(to_uppercase(already_uppercase), NoStdCow::Borrowed(already_uppercase));
+/// assert!(to_uppercase(already_uppercase).is_borrowed());
 /// // This string needs to be converted to uppercase, so a new owned value is constructed
 /// // and returned.
 /// let not_uppercase = "helloworld";
 /// assert_eq!(to_uppercase(not_uppercase), NoStdCow::Owned(String::from("HELLOWORLD")));
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+///
+/// # Custom owned types
+/// `T` can be any type that implements [`Borrow<B>`], including your own types that
+/// borrow as an unrelated `B`.
+/// ```
+/// use core::borrow::Borrow;
+/// use nostd_cow::NoStdCow;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct MyId([u8; 4]);
+/// impl Borrow<[u8]> for MyId {
+///     fn borrow(&self) -> &[u8] {
+///         &self.0
+///     }
+/// }
+///
+/// let bytes = [1, 2, 3, 4];
+/// let borrowed: NoStdCow<'_, MyId, [u8]> = NoStdCow::Borrowed(&bytes);
+/// let owned: NoStdCow<'_, MyId, [u8]> = NoStdCow::Owned(MyId(bytes));
+/// assert_eq!(&*borrowed, &*owned);
+/// assert!(owned.contains(&3));
+/// assert_eq!(owned.into_owned_or(MyId([0; 4])), MyId(bytes));
+/// ```
+#[derive(Debug, Clone)]
 pub enum NoStdCow<'a, T: Borrow<B>, B: ?Sized> {
     /// A borrowed version of `T`. In the most cases, `T` and `B` are the same type.
     Borrowed(&'a B),
@@ -94,6 +152,23 @@ pub enum NoStdCow<'a, T: Borrow<B>, B: ?Sized> {
     Owned(T),
 }
 
+/// Dereferences to the borrowed form of the contents, so methods of `B` can be
+/// called directly on the cow.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let text: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("key=value"));
+/// assert!(text.contains('='));
+/// assert!(text.contains("value"));
+/// assert!(!text.contains(char::is_whitespace));
+/// assert_eq!(text.find('='), Some(3));
+/// assert_eq!(text.find(|c: char| c == 'v'), Some(4));
+///
+/// let bytes: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+/// assert!(bytes.contains(&2));
+/// ```
 impl<T: Borrow<B>, B: ?Sized> Deref for NoStdCow<'_, T, B> {
     type Target = B;
     fn deref(&self) -> &Self::Target {
@@ -103,8 +178,24 @@ impl<T: Borrow<B>, B: ?Sized> Deref for NoStdCow<'_, T, B> {
         }
     }
 }
-impl<T: Borrow<B>, B: ?Sized> NoStdCow<'_, T, B> {
+impl<'a, T: Borrow<B>, B: ?Sized> NoStdCow<'a, T, B> {
     /// Returns true if the data is borrowed, i.e. if `to_mut` would require additional work.
+    ///
+    /// This is a `const fn`, so it can be used in `const` and `static` initializers.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::RefCow;
+    ///
+    /// const COW: RefCow<'static, i32> = RefCow::Borrowed(&5);
+    /// const BORROWED: bool = COW.is_borrowed();
+    /// static OWNED: bool = RefCow::<i32>::Owned(5).is_borrowed();
+    /// assert!(BORROWED);
+    /// assert!(!OWNED);
+    ///
+    /// struct Flag<const B: bool>;
+    /// let _: Flag<true> = Flag::<{ COW.is_borrowed() }>;
+    /// ```
     pub const fn is_borrowed(&self) -> bool {
         match self {
             Self::Borrowed(_) => true,
@@ -112,12 +203,532 @@ impl<T: Borrow<B>, B: ?Sized> NoStdCow<'_, T, B> {
         }
     }
     /// Returns true if the data is owned, i.e. if `to_mut` would be a no-op.
+    ///
+    /// This is a `const fn`, so it can be used in `const` and `static` initializers.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::RefCow;
+    ///
+    /// const COW: RefCow<'static, i32> = RefCow::Owned(5);
+    /// const OWNED: bool = COW.is_owned();
+    /// static BORROWED: bool = RefCow::Borrowed(&5).is_owned();
+    /// assert!(OWNED);
+    /// assert!(!BORROWED);
+    ///
+    /// let buffer = [0u8; COW.is_owned() as usize];
+    /// assert_eq!(buffer.len(), 1);
+    /// ```
     pub const fn is_owned(&self) -> bool {
         match self {
             Self::Borrowed(_) => false,
             Self::Owned(_) => true,
         }
     }
+    /// Returns a reference to the borrowed form of the contents.
+    ///
+    /// This is the same as [`Deref::deref`], but can be called by name in generic
+    /// code and macros where auto-deref doesn't kick in.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let cow: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("hello"));
+    /// assert_eq!(cow.as_borrowed(), &*cow);
+    /// // Methods of `B` with the same name are still reachable through deref.
+    /// assert_eq!(cow.get(0..1), Some("h"));
+    ///
+    /// let slice: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2]);
+    /// assert_eq!(slice.as_borrowed(), &[1, 2]);
+    /// assert_eq!(slice.get(1), Some(&2));
+    /// ```
+    pub fn as_borrowed(&self) -> &B {
+        self.deref()
+    }
+    /// Dereferences the contents one more level, like [`Option::as_deref`].
+    ///
+    /// This is available when `B` itself implements [`Deref`], and the returned
+    /// reference borrows from `self` regardless of the variant.
+    ///
+    /// # Example
+    /// ```
+    /// use core::ops::Deref;
+    /// use nostd_cow::RefCow;
+    ///
+    /// struct Name(String);
+    /// impl Deref for Name {
+    ///     type Target = str;
+    ///     fn deref(&self) -> &str {
+    ///         &self.0
+    ///     }
+    /// }
+    ///
+    /// let name = Name(String::from("ferris"));
+    /// let borrowed: RefCow<'_, Name> = RefCow::Borrowed(&name);
+    /// let owned: RefCow<'_, Name> = RefCow::Owned(Name(String::from("ferris")));
+    /// assert_eq!(borrowed.as_deref(), "ferris");
+    /// assert_eq!(owned.as_deref(), "ferris");
+    /// ```
+    pub fn as_deref(&self) -> &<B as Deref>::Target
+    where
+        B: Deref,
+    {
+        self.deref().deref()
+    }
+    /// Returns a mutable reference to the owned value, or `None` if the data is borrowed.
+    ///
+    /// Unlike `to_mut`, this never clones, so it is available without any [`Clone`]
+    /// bound and leaves borrowed cows untouched.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::RefCow;
+    ///
+    /// let value = 1;
+    /// let mut borrowed = RefCow::Borrowed(&value);
+    /// assert_eq!(borrowed.borrow_mut_owned(), None);
+    ///
+    /// let mut owned: RefCow<'_, i32> = RefCow::Owned(1);
+    /// *owned.borrow_mut_owned().unwrap() += 1;
+    /// assert_eq!(owned, RefCow::Owned(2));
+    /// ```
+    pub fn borrow_mut_owned(&mut self) -> Option<&mut T> {
+        match self {
+            Self::Borrowed(_) => None,
+            Self::Owned(v) => Some(v),
+        }
+    }
+    /// Replaces the contents with `Borrowed(b)` if they are empty, then returns a
+    /// reference to the (possibly new) contents.
+    ///
+    /// Emptiness is decided by [`CowLen`]. Use [`NoStdCow::or_insert_borrowed_if`] to
+    /// supply your own predicate.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut empty: NoStdCow<'_, String, str> = NoStdCow::Owned(String::new());
+    /// assert_eq!(empty.or_insert_borrowed("default"), "default");
+    /// assert!(empty.is_borrowed());
+    ///
+    /// let mut filled: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1]);
+    /// assert_eq!(filled.or_insert_borrowed(&[0, 0]), [1]);
+    /// assert!(filled.is_owned());
+    /// ```
+    pub fn or_insert_borrowed(&mut self, b: &'a B) -> &B
+    where
+        B: CowLen,
+    {
+        self.or_insert_borrowed_if(b, B::cow_is_empty)
+    }
+    /// Replaces the contents with `Borrowed(b)` if `is_empty` returns true for the
+    /// current contents, then returns a reference to the (possibly new) contents.
+    ///
+    /// Cows whose contents are not considered empty are left untouched. This allows
+    /// lazily assigning a borrowed default without allocating.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut blank: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("  "));
+    /// assert_eq!(blank.or_insert_borrowed_if("default", |s| s.trim().is_empty()), "default");
+    /// assert!(blank.is_borrowed());
+    ///
+    /// let mut filled: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("set"));
+    /// assert_eq!(filled.or_insert_borrowed_if("default", str::is_empty), "set");
+    /// assert!(filled.is_owned());
+    /// ```
+    pub fn or_insert_borrowed_if<F: FnOnce(&B) -> bool>(&mut self, b: &'a B, is_empty: F) -> &B {
+        if is_empty(&**self) {
+            *self = Self::Borrowed(b);
+        }
+        self
+    }
+    /// Constructs a [`NoStdCow::Borrowed`] only if `validate` accepts `b`.
+    ///
+    /// The closure is run exactly once and its error is returned unchanged on failure.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// fn ascii(s: &str) -> Result<(), &'static str> {
+    ///     if s.is_ascii() { Ok(()) } else { Err("not ascii") }
+    /// }
+    /// let ok: Result<NoStdCow<'_, String, str>, _> = NoStdCow::try_borrowed("hello", ascii);
+    /// assert_eq!(ok, Ok(NoStdCow::Borrowed("hello")));
+    /// assert!(ok.unwrap().is_borrowed());
+    /// let err: Result<NoStdCow<'_, String, str>, _> = NoStdCow::try_borrowed("héllo", ascii);
+    /// assert_eq!(err, Err("not ascii"));
+    /// ```
+    pub fn try_borrowed<E, F: FnOnce(&'a B) -> Result<(), E>>(b: &'a B, validate: F) -> Result<Self, E> {
+        validate(b)?;
+        Ok(Self::Borrowed(b))
+    }
+    /// Copies the bytes of the contents into `buf` and returns a borrowed cow over
+    /// the written region.
+    ///
+    /// The returned cow is always [`NoStdCow::Borrowed`], so its owned type `O` can
+    /// be chosen freely. Fails with [`BufTooSmall`] without writing anything if `buf`
+    /// is shorter than the contents.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::{BufTooSmall, NoStdCow};
+    ///
+    /// let cow: NoStdCow<'_, String, str> = NoStdCow::Borrowed("abc");
+    ///
+    /// let mut exact = [0u8; 3];
+    /// let written: NoStdCow<'_, Vec<u8>, [u8]> = cow.render_into(&mut exact).unwrap();
+    /// assert_eq!(&*written, b"abc");
+    ///
+    /// let mut larger = [0u8; 8];
+    /// let written: NoStdCow<'_, Vec<u8>, [u8]> = cow.render_into(&mut larger).unwrap();
+    /// assert_eq!(&*written, b"abc");
+    ///
+    /// let mut smaller = [0u8; 2];
+    /// let err = cow.render_into::<Vec<u8>>(&mut smaller).unwrap_err();
+    /// assert_eq!(err, BufTooSmall { required: 3, available: 2 });
+    /// ```
+    pub fn render_into<'b, O: Borrow<[u8]>>(&self, buf: &'b mut [u8]) -> Result<NoStdCow<'b, O, [u8]>, BufTooSmall>
+    where
+        B: AsRef<[u8]>,
+    {
+        let bytes = self.deref().as_ref();
+        if buf.len() < bytes.len() {
+            return Err(BufTooSmall { required: bytes.len(), available: buf.len() });
+        }
+        let dest = &mut buf[..bytes.len()];
+        dest.copy_from_slice(bytes);
+        Ok(NoStdCow::Borrowed(dest))
+    }
+    /// Extracts the owned data, or returns `if_borrowed` if the data is borrowed.
+    ///
+    /// Unlike `into_owned`, this never clones, so it is available without any
+    /// [`Clone`] bound. The borrowed contents are discarded.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("owned"));
+    /// assert_eq!(owned.into_owned_or(String::from("fallback")), "owned");
+    ///
+    /// let borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("borrowed");
+    /// assert_eq!(borrowed.into_owned_or(String::from("fallback")), "fallback");
+    /// ```
+    pub fn into_owned_or(self, if_borrowed: T) -> T {
+        match self {
+            Self::Borrowed(_) => if_borrowed,
+            Self::Owned(v) => v,
+        }
+    }
+    /// Applies a borrowing projection to the contents, returning a borrowed cow over
+    /// the narrowed type.
+    ///
+    /// The result always borrows from `self`, so no data is copied for either variant.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::RefCow;
+    ///
+    /// #[derive(Clone)]
+    /// struct Config {
+    ///     name: String,
+    ///     port: u16,
+    /// }
+    ///
+    /// let config = Config { name: String::from("server"), port: 80 };
+    /// let borrowed = RefCow::Borrowed(&config);
+    /// let owned = RefCow::Owned(config.clone());
+    /// assert_eq!(&*borrowed.project(|c| c.name.as_str()), "server");
+    /// assert_eq!(*owned.project(|c| &c.port), 80);
+    /// assert!(owned.project(|c| &c.port).is_borrowed());
+    /// ```
+    pub fn project<C: ?Sized, F>(&self, f: F) -> NoStdCow<'_, &C, C>
+    where
+        F: FnOnce(&B) -> &C,
+    {
+        NoStdCow::Borrowed(f(self))
+    }
+    /// Constructs an owned cow from `owned` if it is `Some`, otherwise borrows `borrowed`.
+    ///
+    /// The owned value always takes precedence when present.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let owned: NoStdCow<'_, String, str> = NoStdCow::from_parts(Some(String::from("owned")), "borrowed");
+    /// assert_eq!(owned, NoStdCow::Owned(String::from("owned")));
+    /// assert!(owned.is_owned());
+    ///
+    /// let borrowed: NoStdCow<'_, String, str> = NoStdCow::from_parts(None, "borrowed");
+    /// assert_eq!(borrowed, NoStdCow::Borrowed("borrowed"));
+    /// assert!(borrowed.is_borrowed());
+    /// ```
+    pub fn from_parts(owned: Option<T>, borrowed: &'a B) -> Self {
+        match owned {
+            Some(v) => Self::Owned(v),
+            None => Self::Borrowed(borrowed),
+        }
+    }
+    /// Constructs a cow from a [`Result`] holding either a borrow or an owned value.
+    ///
+    /// `Ok(borrowed)` becomes [`NoStdCow::Borrowed`] and `Err(owned)` becomes
+    /// [`NoStdCow::Owned`], which suits functions that return the input unchanged on
+    /// the fast path and a freshly built value otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// fn trimmed(s: &str) -> Result<&str, String> {
+    ///     if s.trim() == s { Ok(s) } else { Err(String::from(s.trim())) }
+    /// }
+    ///
+    /// let borrowed: NoStdCow<'_, String, str> = NoStdCow::from_result(trimmed("clean"));
+    /// assert_eq!(borrowed, NoStdCow::Borrowed("clean"));
+    /// assert!(borrowed.is_borrowed());
+    ///
+    /// let owned: NoStdCow<'_, String, str> = NoStdCow::from_result(trimmed(" padded "));
+    /// assert_eq!(owned, NoStdCow::Owned(String::from("padded")));
+    /// assert!(owned.is_owned());
+    /// ```
+    pub fn from_result(r: Result<&'a B, T>) -> Self {
+        match r {
+            Ok(b) => Self::Borrowed(b),
+            Err(v) => Self::Owned(v),
+        }
+    }
+    /// Returns a wrapper whose [`Debug`](fmt::Debug) output is that of the contents,
+    /// without the `Borrowed(..)`/`Owned(..)` variant name.
+    ///
+    /// See [`Transparent`] for details.
+    pub const fn transparent(&self) -> Transparent<'_, 'a, T, B> {
+        Transparent(self)
+    }
+    /// Re-points the cow at `b`, dropping any previously owned value.
+    ///
+    /// # Example
+    /// ```
+    /// use std::rc::Rc;
+    /// use nostd_cow::RefCow;
+    ///
+    /// let (first, second) = (Rc::new(1), Rc::new(2));
+    /// let mut cow = RefCow::Borrowed(&first);
+    /// cow.set_borrowed(&second);
+    /// assert_eq!(**cow, 2);
+    ///
+    /// let mut owned = RefCow::Owned(Rc::clone(&first));
+    /// assert_eq!(Rc::strong_count(&first), 2);
+    /// owned.set_borrowed(&second);
+    /// // The owned clone has been dropped.
+    /// assert_eq!(Rc::strong_count(&first), 1);
+    /// assert!(owned.is_borrowed());
+    /// ```
+    pub fn set_borrowed(&mut self, b: &'a B) {
+        *self = Self::Borrowed(b);
+    }
+    /// Replaces owned contents with a borrow of the first equal value in `candidates`,
+    /// dropping the owned value.
+    ///
+    /// Candidates are checked in order and the first match wins. Owned contents
+    /// without a match, and borrowed cows, are left untouched.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// static KNOWN: [&str; 3] = ["GET", "POST", "GET"];
+    ///
+    /// let mut matching: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("get").to_uppercase());
+    /// matching.normalize(&KNOWN);
+    /// assert!(matching.is_borrowed());
+    /// assert_eq!(matching.as_ptr(), KNOWN[0].as_ptr());
+    ///
+    /// let mut other: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("PUT"));
+    /// other.normalize(&KNOWN);
+    /// assert_eq!(other, NoStdCow::Owned(String::from("PUT")));
+    /// assert!(other.is_owned());
+    ///
+    /// // The candidate list itself only needs to live for the call.
+    /// let (get, head) = (String::from("GET"), String::from("HEAD"));
+    /// let mut local: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("HEAD"));
+    /// local.normalize(&[get.as_str(), head.as_str()]);
+    /// assert_eq!(local.as_ptr(), head.as_ptr());
+    /// ```
+    pub fn normalize(&mut self, candidates: &[&'a B])
+    where
+        B: PartialEq,
+    {
+        if self.is_borrowed() {
+            return;
+        }
+        if let Some(&candidate) = candidates.iter().find(|candidate| ***candidate == **self) {
+            *self = Self::Borrowed(candidate);
+        }
+    }
+    /// Builds a cow from an iterator of borrowed pieces, borrowing when there is
+    /// exactly one piece.
+    ///
+    /// - With exactly one piece, it is returned as [`NoStdCow::Borrowed`] without
+    ///   allocating.
+    /// - With zero or several pieces, they are collected into an owned `T` with
+    ///   [`FromIterator`], so zero pieces give an owned empty value.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let none: NoStdCow<'_, String, str> = NoStdCow::from_iter_borrowed([]);
+    /// assert_eq!(none, NoStdCow::Owned(String::new()));
+    /// assert!(none.is_owned());
+    ///
+    /// let one: NoStdCow<'_, String, str> = NoStdCow::from_iter_borrowed(["a"]);
+    /// assert_eq!(one, NoStdCow::Borrowed("a"));
+    /// assert!(one.is_borrowed());
+    ///
+    /// let many: NoStdCow<'_, String, str> = NoStdCow::from_iter_borrowed(["a", "b", "c"]);
+    /// assert_eq!(many, NoStdCow::Owned(String::from("abc")));
+    /// assert!(many.is_owned());
+    /// ```
+    pub fn from_iter_borrowed<I: IntoIterator<Item = &'a B>>(iter: I) -> Self
+    where
+        T: FromIterator<&'a B>,
+    {
+        let mut iter = iter.into_iter();
+        let Some(first) = iter.next() else {
+            return Self::Owned(T::from_iter(core::iter::empty()));
+        };
+        let Some(second) = iter.next() else {
+            return Self::Borrowed(first);
+        };
+        Self::Owned([first, second].into_iter().chain(iter).collect())
+    }
+    /// Constructs a [`NoStdCow::Borrowed`] pointing into an existing owned value.
+    ///
+    /// The cow borrows from `owned` for its whole lifetime `'a`, so nothing is cloned.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let cached = String::from("cached");
+    /// let cow: NoStdCow<'_, String, str> = NoStdCow::borrow_from_owned(&cached);
+    /// assert!(cow.is_borrowed());
+    /// assert_eq!(cow.as_ptr(), cached.as_ptr());
+    ///
+    /// let buffer = vec![1u8, 2, 3];
+    /// let cow: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::borrow_from_owned(&buffer);
+    /// assert_eq!(cow.as_ptr(), buffer.as_ptr());
+    /// ```
+    pub fn borrow_from_owned(owned: &'a T) -> Self {
+        Self::Borrowed(owned.borrow())
+    }
+    /// Extracts the owned data, or returns `T::default()` if the data is borrowed.
+    ///
+    /// Unlike `into_owned`, this never clones. The borrowed contents are discarded.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("owned"));
+    /// assert_eq!(owned.into_owned_or_default(), "owned");
+    ///
+    /// let borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("borrowed");
+    /// assert_eq!(borrowed.into_owned_or_default(), "");
+    /// ```
+    pub fn into_owned_or_default(self) -> T
+    where
+        T: Default,
+    {
+        match self {
+            Self::Borrowed(_) => T::default(),
+            Self::Owned(v) => v,
+        }
+    }
+    /// Returns the owned data as `Ok`, or `Err(err)` if the data is borrowed.
+    ///
+    /// This treats "must be owned by now" as a recoverable condition instead of
+    /// panicking, and never clones. The borrowed contents are discarded in the
+    /// error case.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("owned"));
+    /// assert_eq!(owned.ok_or("still borrowed"), Ok(String::from("owned")));
+    ///
+    /// let borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("borrowed");
+    /// assert_eq!(borrowed.ok_or("still borrowed"), Err("still borrowed"));
+    /// ```
+    pub fn ok_or<E>(self, err: E) -> Result<T, E> {
+        match self {
+            Self::Borrowed(_) => Err(err),
+            Self::Owned(v) => Ok(v),
+        }
+    }
+}
+impl<'a, T: Borrow<B>, B: ?Sized> RefCow<'a, NoStdCow<'a, T, B>> {
+    /// Collapses a cow of cows into a single cow, staying zero-copy where possible.
+    ///
+    /// - An owned outer cow yields the inner cow unchanged.
+    /// - A borrowed outer cow yields a [`NoStdCow::Borrowed`] pointing at the inner
+    ///   contents, whether the inner cow is borrowed or owned, so nothing is cloned.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::{NoStdCow, RefCow};
+    ///
+    /// let inner_borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("a");
+    /// let inner_owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("b"));
+    ///
+    /// let borrowed_in_borrowed = RefCow::Borrowed(&inner_borrowed).flatten();
+    /// assert_eq!(borrowed_in_borrowed, NoStdCow::Borrowed("a"));
+    /// assert!(borrowed_in_borrowed.is_borrowed());
+    ///
+    /// let owned_in_borrowed = RefCow::Borrowed(&inner_owned).flatten();
+    /// assert_eq!(owned_in_borrowed, NoStdCow::Borrowed("b"));
+    /// assert!(owned_in_borrowed.is_borrowed());
+    ///
+    /// let owned_in_owned = RefCow::Owned(NoStdCow::<'_, String, str>::Owned(String::from("c"))).flatten();
+    /// assert!(owned_in_owned.is_owned());
+    /// ```
+    pub fn flatten(self) -> NoStdCow<'a, T, B> {
+        match self {
+            Self::Borrowed(inner) => NoStdCow::Borrowed(inner.deref()),
+            Self::Owned(inner) => inner,
+        }
+    }
+}
+impl<T: Borrow<dyn Any>> NoStdCow<'_, T, dyn Any> {
+    /// Returns a reference to the contents as a `C` if they are of that type.
+    ///
+    /// Forwards to [`<dyn Any>::downcast_ref`](Any#method.downcast_ref), so the
+    /// concrete type is recovered from either variant without cloning.
+    ///
+    /// # Example
+    /// ```
+    /// use core::any::Any;
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let value = 5i32;
+    /// let borrowed: NoStdCow<'_, Box<dyn Any>, dyn Any> = NoStdCow::Borrowed(&value);
+    /// assert_eq!(borrowed.downcast_ref::<i32>(), Some(&5));
+    /// assert_eq!(borrowed.downcast_ref::<u8>(), None);
+    ///
+    /// let owned: NoStdCow<'_, Box<dyn Any>, dyn Any> = NoStdCow::Owned(Box::new("text"));
+    /// assert_eq!(owned.downcast_ref::<&str>(), Some(&"text"));
+    /// ```
+    pub fn downcast_ref<C: Any>(&self) -> Option<&C> {
+        self.deref().downcast_ref()
+    }
 }
 impl<T: Clone + Borrow<T>> RefCow<'_, T> {
     /// Acquires a mutable reference to the owned form of the data.
@@ -150,8 +761,83 @@ impl<T: Clone + Borrow<T>> RefCow<'_, T> {
             Self::Borrowed(v) => v.clone(),
         }
     }
+    /// Runs `f` with mutable access to the owned data and returns its result.
+    ///
+    /// Clones the data first if it is not already owned, just like `to_mut`.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::RefCow;
+    ///
+    /// let values = vec![1, 2];
+    /// let mut borrowed = RefCow::Borrowed(&values);
+    /// let len = borrowed.with_owned(|v| {
+    ///     v.push(3);
+    ///     v.len()
+    /// });
+    /// assert_eq!(len, 3);
+    /// assert_eq!(borrowed, RefCow::Owned(vec![1, 2, 3]));
+    /// assert_eq!(values, [1, 2]);
+    ///
+    /// let mut owned: RefCow<'_, Vec<i32>> = RefCow::Owned(vec![1]);
+    /// assert_eq!(owned.with_owned(|v| v.pop()), Some(1));
+    /// assert_eq!(owned, RefCow::Owned(vec![]));
+    /// ```
+    pub fn with_owned<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> R {
+        f(self.to_mut())
+    }
+    /// Applies `f` to the owned data in place.
+    ///
+    /// An owned value is mutated directly without reallocating, while borrowed data is
+    /// cloned exactly once before `f` is applied. See also [`RefCow::with_owned`],
+    /// which additionally returns the result of the closure.
+    ///
+    /// # Example
+    /// ```
+    /// use std::rc::Rc;
+    /// use nostd_cow::RefCow;
+    ///
+    /// let mut owned: RefCow<'_, Vec<i32>> = RefCow::Owned(Vec::with_capacity(4));
+    /// let ptr = owned.as_ptr();
+    /// owned.map_owned_in_place(|v| v.extend([1, 2]));
+    /// assert_eq!(owned.as_ptr(), ptr);
+    /// assert_eq!(owned, RefCow::Owned(vec![1, 2]));
+    ///
+    /// let shared = Rc::new(5);
+    /// let mut borrowed = RefCow::Borrowed(&shared);
+    /// borrowed.map_owned_in_place(|rc| {
+    ///     // The closure receives a single clone of the borrowed value.
+    ///     assert_eq!(Rc::strong_count(rc), 2);
+    /// });
+    /// assert!(borrowed.is_owned());
+    /// ```
+    pub fn map_owned_in_place<F: FnOnce(&mut T)>(&mut self, f: F) {
+        f(self.to_mut())
+    }
+    /// Converts the cow into one that is guaranteed to be [`NoStdCow::Owned`].
+    ///
+    /// Clones the data if it is not already owned. Unlike `into_owned`, the result is
+    /// still a cow, and since it no longer borrows anything it can have any lifetime,
+    /// including `'static`.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::RefCow;
+    ///
+    /// let value = 5;
+    /// let owned: RefCow<'static, i32> = RefCow::Borrowed(&value).into_owned_cow();
+    /// assert_eq!(owned, RefCow::Owned(5));
+    /// assert!(RefCow::<i32>::Owned(5).into_owned_cow().is_owned());
+    /// ```
+    pub fn into_owned_cow<'b>(self) -> RefCow<'b, T> {
+        RefCow::Owned(self.into_owned())
+    }
 }
 
+/// Builds an owned `T::default()`.
+///
+/// Use [`NoStdCow::default_with`] with [`DefaultBorrowed`] for a default that
+/// doesn't construct an owned value.
 impl<T: Borrow<B> + Default, B: ?Sized> Default for NoStdCow<'_, T, B> {
     fn default() -> Self {
         Self::Owned(T::default())
@@ -162,4 +848,207 @@ impl<'a, T: Borrow<B>, B: ?Sized> From<&'a B> for NoStdCow<'a, T, B> {
     fn from(value: &'a B) -> Self {
         Self::Borrowed(value)
     }
-}
\ No newline at end of file
+}
+
+/// Compares the borrowed form of the contents, so a borrowed and an owned cow
+/// holding the same data are equal, just like `Cow`.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("grüße, 世界");
+/// let owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("grüße, 世界"));
+/// assert_eq!(borrowed, owned);
+/// assert_eq!(borrowed.as_bytes(), owned.as_bytes());
+/// assert_ne!(owned, NoStdCow::Borrowed("grüsse, 世界"));
+/// // Precomposed and decomposed forms differ in bytes, so they are not equal.
+/// assert_ne!(owned, NoStdCow::Borrowed("gru\u{308}ße, 世界"));
+/// ```
+impl<'b, T: Borrow<B>, B: ?Sized + PartialEq> PartialEq<NoStdCow<'b, T, B>> for NoStdCow<'_, T, B> {
+    fn eq(&self, other: &NoStdCow<'b, T, B>) -> bool {
+        self.deref() == other.deref()
+    }
+}
+
+impl<T: Borrow<B>, B: ?Sized + Eq> Eq for NoStdCow<'_, T, B> {}
+
+/// Orders cows by the borrowed form of their contents, regardless of the variant.
+impl<'b, T: Borrow<B>, B: ?Sized + PartialOrd> PartialOrd<NoStdCow<'b, T, B>> for NoStdCow<'_, T, B> {
+    fn partial_cmp(&self, other: &NoStdCow<'b, T, B>) -> Option<Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+/// Orders cows by the borrowed form of their contents, regardless of the variant.
+///
+/// This also provides [`Ord::max`] and [`Ord::min`], which choose between two cows by
+/// content and return the winner as is, without cloning or changing its variant.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let small = || NoStdCow::<'_, String, str>::Owned(String::from("apple"));
+/// let large = || NoStdCow::<'_, String, str>::Borrowed("banana");
+/// let max = small().max(large());
+/// assert_eq!(max, NoStdCow::Borrowed("banana"));
+/// assert!(max.is_borrowed());
+/// let min = small().min(large());
+/// assert_eq!(min, NoStdCow::Owned(String::from("apple")));
+/// assert!(min.is_owned());
+/// ```
+impl<T: Borrow<B>, B: ?Sized + Ord> Ord for NoStdCow<'_, T, B> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+/// Hashes the borrowed form of the contents, so a borrowed and an owned cow
+/// holding the same data produce the same hash, just like `Cow`.
+///
+/// # Example
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+/// use nostd_cow::RefCow;
+///
+/// fn hash_of<H: Hash>(value: &H) -> u64 {
+///     let mut hasher = DefaultHasher::new();
+///     value.hash(&mut hasher);
+///     hasher.finish()
+/// }
+///
+/// let array = [1u8, 2, 3, 4];
+/// assert_eq!(hash_of(&RefCow::Borrowed(&array)), hash_of(&RefCow::Owned(array)));
+/// assert_eq!(hash_of(&RefCow::Borrowed(&array)), hash_of(&array));
+///
+/// let tuple = (1u8, 'a');
+/// assert_eq!(hash_of(&RefCow::Borrowed(&tuple)), hash_of(&RefCow::Owned(tuple)));
+/// ```
+///
+/// Slice and `str` cows hash exactly like the raw slice, including its length
+/// prefix, so adjacent cows in a composite key can't collide by shifting
+/// elements between them.
+/// ```
+/// # use std::collections::hash_map::DefaultHasher;
+/// # use std::hash::{Hash, Hasher};
+/// # fn hash_of<H: Hash + ?Sized>(value: &H) -> u64 {
+/// #     let mut hasher = DefaultHasher::new();
+/// #     value.hash(&mut hasher);
+/// #     hasher.finish()
+/// # }
+/// use nostd_cow::NoStdCow;
+///
+/// let raw: &[u8] = &[1, 2, 3];
+/// let borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(raw);
+/// let owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(raw.to_vec());
+/// assert_eq!(hash_of(&borrowed), hash_of(raw));
+/// assert_eq!(hash_of(&owned), hash_of(raw));
+///
+/// let text: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("abc"));
+/// assert_eq!(hash_of(&text), hash_of("abc"));
+///
+/// type Key<'a> = (NoStdCow<'a, Vec<u8>, [u8]>, NoStdCow<'a, Vec<u8>, [u8]>);
+/// let left: Key<'_> = (NoStdCow::Borrowed(&[1, 2]), NoStdCow::Borrowed(&[3]));
+/// let right: Key<'_> = (NoStdCow::Borrowed(&[1]), NoStdCow::Borrowed(&[2, 3]));
+/// assert_eq!(hash_of(&left), hash_of(&(&[1u8, 2][..], &[3u8][..])));
+/// assert_ne!(hash_of(&left), hash_of(&right));
+/// ```
+impl<T: Borrow<B>, B: ?Sized + Hash> Hash for NoStdCow<'_, T, B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.deref().hash(state)
+    }
+}
+
+/// Displays the borrowed form of the contents, so both variants print the same.
+///
+/// Formatting is forwarded to `B`'s own [`Display`](fmt::Display) with the same
+/// [`Formatter`](fmt::Formatter), so flags are honored exactly as for `B`. For `str`
+/// cows this goes through [`Formatter::pad`](fmt::Formatter::pad), which applies
+/// width, precision, fill and alignment.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("cow");
+/// let owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("cow"));
+/// assert_eq!(format!("{}", borrowed), "cow");
+/// assert_eq!(format!("{:>6}|", owned), format!("{:>6}|", "cow"));
+/// assert_eq!(format!("{:<6}|", borrowed), "cow   |");
+/// assert_eq!(format!("{:.2}", owned), format!("{:.2}", "cow"));
+/// assert_eq!(format!("{:*^7.2}", borrowed), "**co***");
+/// ```
+impl<T: Borrow<B>, B: ?Sized + fmt::Display> fmt::Display for NoStdCow<'_, T, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.deref(), f)
+    }
+}
+
+/// The error returned by [`NoStdCow::render_into`] when the destination buffer is
+/// too small to hold the contents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufTooSmall {
+    /// The number of bytes that needed to be written.
+    pub required: usize,
+    /// The length of the buffer that was provided.
+    pub available: usize,
+}
+
+impl fmt::Display for BufTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "buffer too small: {} bytes required but only {} available", self.required, self.available)
+    }
+}
+
+/// A wrapper around a [`NoStdCow`] reference whose [`Debug`](fmt::Debug) output
+/// forwards directly to the contents.
+///
+/// The derived `Debug` of [`NoStdCow`] prints the variant name, which is noisy in
+/// logs. Wrapping a cow with [`NoStdCow::transparent`] omits it.
+///
+/// # Example
+/// ```
+/// use nostd_cow::{NoStdCow, Transparent};
+///
+/// let borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("hello");
+/// let owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("hello"));
+/// assert_eq!(format!("{:?}", borrowed), r#"Borrowed("hello")"#);
+/// assert_eq!(format!("{:?}", owned), r#"Owned("hello")"#);
+/// assert_eq!(format!("{:?}", borrowed.transparent()), r#""hello""#);
+/// assert_eq!(format!("{:?}", Transparent(&owned)), r#""hello""#);
+/// ```
+pub struct Transparent<'c, 'a, T: Borrow<B>, B: ?Sized>(pub &'c NoStdCow<'a, T, B>);
+
+impl<T: Borrow<B>, B: ?Sized + fmt::Debug> fmt::Debug for Transparent<'_, '_, T, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.0.deref(), f)
+    }
+}
+
+/// Reflexive [`AsRef`] so cows can be passed to APIs bounded by `AsRef<NoStdCow<..>>`.
+///
+/// The reflexive `Borrow<Self>` is already provided by `core`'s blanket implementation.
+///
+/// # Example
+/// ```
+/// use core::borrow::Borrow;
+/// use nostd_cow::NoStdCow;
+///
+/// fn len_as_ref<C: AsRef<NoStdCow<'static, String, str>>>(cow: C) -> usize {
+///     cow.as_ref().len()
+/// }
+/// fn len_borrow<C: Borrow<NoStdCow<'static, String, str>>>(cow: C) -> usize {
+///     cow.borrow().len()
+/// }
+/// let cow: NoStdCow<'static, String, str> = NoStdCow::Borrowed("hello");
+/// assert_eq!(len_as_ref(&cow), 5);
+/// assert_eq!(len_borrow(&cow), 5);
+/// assert_eq!(len_as_ref(cow), 5);
+/// ```
+impl<'a, T: Borrow<B>, B: ?Sized> AsRef<NoStdCow<'a, T, B>> for NoStdCow<'a, T, B> {
+    fn as_ref(&self) -> &NoStdCow<'a, T, B> {
+        self
+    }
+}
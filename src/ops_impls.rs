@@ -0,0 +1,117 @@
+use crate::RefCow;
+use core::borrow::Borrow;
+use core::ops::{Add, Deref, Mul, Neg, Not, Sub};
+
+/// Negates the contents, producing an owned result.
+///
+/// # Example
+/// ```
+/// use nostd_cow::RefCow;
+///
+/// let value = 5;
+/// assert_eq!(-RefCow::Borrowed(&value), RefCow::Owned(-5));
+/// assert_eq!(-RefCow::<i32>::Owned(-3), RefCow::Owned(3));
+/// ```
+impl<'a, T: Borrow<T>> Neg for RefCow<'a, T>
+where
+    for<'x> &'x T: Neg<Output = T>,
+{
+    type Output = RefCow<'a, T>;
+    fn neg(self) -> Self::Output {
+        RefCow::Owned(-self.deref())
+    }
+}
+
+/// Negates a boolean flag, producing an owned result.
+///
+/// # Example
+/// ```
+/// use nostd_cow::RefCow;
+///
+/// let flag = true;
+/// assert_eq!(!RefCow::Borrowed(&flag), RefCow::Owned(false));
+/// assert_eq!(!RefCow::<bool>::Owned(false), RefCow::Owned(true));
+/// ```
+impl<'a> Not for RefCow<'a, bool> {
+    type Output = RefCow<'a, bool>;
+    fn not(self) -> Self::Output {
+        RefCow::Owned(!*self)
+    }
+}
+
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $op:tt, $lhs:literal, $rhs:literal, $result:literal) => {
+        #[doc = concat!("Applies `", stringify!($op), "` to the contents of both cows, producing an owned result.")]
+        ///
+        /// # Example
+        /// ```
+        /// use nostd_cow::RefCow;
+        ///
+        #[doc = concat!("let (lhs, rhs): (i32, i32) = (", $lhs, ", ", $rhs, ");")]
+        #[doc = concat!("let expected = RefCow::Owned(", $result, ");")]
+        #[doc = concat!("assert_eq!(RefCow::Borrowed(&lhs) ", stringify!($op), " RefCow::Borrowed(&rhs), expected);")]
+        #[doc = concat!("assert_eq!(RefCow::Borrowed(&lhs) ", stringify!($op), " RefCow::Owned(rhs), expected);")]
+        #[doc = concat!("assert_eq!(RefCow::Owned(lhs) ", stringify!($op), " RefCow::Borrowed(&rhs), expected);")]
+        #[doc = concat!("assert_eq!(RefCow::<i32>::Owned(lhs) ", stringify!($op), " RefCow::Owned(rhs), expected);")]
+        /// ```
+        impl<'a, 'b, T: Borrow<T>> $trait<RefCow<'b, T>> for RefCow<'a, T>
+        where
+            for<'x, 'y> &'x T: $trait<&'y T, Output = T>,
+        {
+            type Output = RefCow<'a, T>;
+            fn $method(self, rhs: RefCow<'b, T>) -> Self::Output {
+                RefCow::Owned(self.deref() $op rhs.deref())
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, +, "7", "3", "10");
+impl_binary_op!(Sub, sub, -, "7", "3", "4");
+impl_binary_op!(Mul, mul, *, "7", "3", "21");
+
+/// Integer types with a checked addition, enabling [`RefCow::checked_add_cow`].
+///
+/// This is implemented for all primitive integer types.
+pub trait CowCheckedAdd: Sized {
+    /// Adds `rhs`, returning `None` on overflow.
+    fn cow_checked_add(&self, rhs: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_add {
+    ($($int:ty),*) => {
+        $(
+            impl CowCheckedAdd for $int {
+                fn cow_checked_add(&self, rhs: &Self) -> Option<Self> {
+                    self.checked_add(*rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_add!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: CowCheckedAdd + Borrow<T>> RefCow<'_, T> {
+    /// Adds the contents of both cows, returning an owned result or `None` on
+    /// overflow.
+    ///
+    /// Unlike `+`, this never panics or wraps, which suits accumulators that keep a
+    /// running total in a cow. The integer `checked_add` is still reachable through
+    /// deref.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::RefCow;
+    ///
+    /// let (lhs, rhs) = (200u8, 50u8);
+    /// assert_eq!(RefCow::Borrowed(&lhs).checked_add_cow(&RefCow::Borrowed(&rhs)), Some(RefCow::Owned(250)));
+    /// assert!(RefCow::Borrowed(&lhs).checked_add_cow(&RefCow::Owned(rhs)).unwrap().is_owned());
+    /// assert_eq!(RefCow::<u8>::Owned(lhs).checked_add_cow(&RefCow::Owned(100)), None);
+    /// assert_eq!(RefCow::<i32>::Owned(i32::MIN).checked_add_cow(&RefCow::Owned(-1)), None);
+    /// assert_eq!(RefCow::Borrowed(&lhs).checked_add(5), Some(205));
+    /// ```
+    pub fn checked_add_cow(&self, rhs: &Self) -> Option<Self> {
+        self.deref().cow_checked_add(rhs.deref()).map(RefCow::Owned)
+    }
+}
@@ -0,0 +1,70 @@
+use crate::NoStdCow;
+use core::borrow::Borrow;
+use core::ops::Deref;
+use serde::{Serialize, Serializer};
+
+/// Serializes the borrowed form of the contents, so both variants produce the
+/// same output.
+///
+/// Since the contents are serialized directly, `str` cows use `serialize_str` and
+/// come out as natural strings rather than sequences.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("hello");
+/// let owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("hello"));
+/// assert_eq!(serde_json::to_string(&borrowed).unwrap(), r#""hello""#);
+/// assert_eq!(serde_json::to_string(&owned).unwrap(), r#""hello""#);
+///
+/// // There is no `Deserialize` impl, but the output reads back as the owned type.
+/// let json = serde_json::to_string(&owned).unwrap();
+/// let back: String = serde_json::from_str(&json).unwrap();
+/// assert_eq!(owned, NoStdCow::Borrowed(back.as_str()));
+///
+/// let bytes: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+/// let json = serde_json::to_string(&bytes).unwrap();
+/// assert_eq!(json, "[1,2,3]");
+/// let back: Vec<u8> = serde_json::from_str(&json).unwrap();
+/// assert_eq!(bytes, NoStdCow::Owned(back));
+/// ```
+impl<T: Borrow<B>, B: ?Sized + Serialize> Serialize for NoStdCow<'_, T, B> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.deref().serialize(serializer)
+    }
+}
+
+impl<T: Borrow<[u8]>> NoStdCow<'_, T, [u8]> {
+    /// Serializes the contents with `serialize_bytes` instead of as a sequence.
+    ///
+    /// The [`Serialize`] implementation forwards to `[u8]`, which serde treats as a
+    /// sequence of integers, so byte cows come out as arrays unless you opt in.
+    /// Binary formats such as CBOR then encode one item per byte rather than a
+    /// byte string. Use this with `#[serde(serialize_with = "...")]` to get a compact
+    /// byte string in formats that support one. When reading the field back, the
+    /// matching deserializer must accept byte strings, e.g. `serde_bytes::ByteBuf`,
+    /// since `Vec<u8>` only accepts sequences in most formats.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Packet<'a> {
+    ///     #[serde(serialize_with = "NoStdCow::serialize_bytes")]
+    ///     payload: NoStdCow<'a, Vec<u8>, [u8]>,
+    /// }
+    ///
+    /// let packet = Packet { payload: NoStdCow::Borrowed(&[1, 2, 3]) };
+    /// // JSON has no byte strings, so serde_json writes bytes as an array.
+    /// let json = serde_json::to_string(&packet).unwrap();
+    /// assert_eq!(json, r#"{"payload":[1,2,3]}"#);
+    /// let back: serde_json::Value = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(back["payload"], serde_json::json!([1, 2, 3]));
+    /// ```
+    pub fn serialize_bytes<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.deref())
+    }
+}
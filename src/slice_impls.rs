@@ -0,0 +1,489 @@
+use crate::NoStdCow;
+use core::borrow::Borrow;
+use core::ops::{Deref, Range};
+
+/// The array head and borrowed remainder returned by [`NoStdCow::split_array`].
+type SplitArray<'s, T, U, const N: usize> = (&'s [U; N], NoStdCow<'s, T, [U]>);
+
+impl<U, T: Borrow<[U]>> NoStdCow<'_, T, [U]> {
+    /// Returns the index of the first element matching the predicate, if any.
+    ///
+    /// Forwards to [`Iterator::position`] over the contents.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+    /// assert_eq!(borrowed.position(|x| *x == 2), Some(1));
+    /// assert_eq!(borrowed.position(|x| *x == 4), None);
+    ///
+    /// let owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+    /// assert_eq!(owned.position(|x| *x > 1), Some(1));
+    ///
+    /// let empty: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(Vec::new());
+    /// assert_eq!(empty.position(|_| true), None);
+    /// ```
+    pub fn position<F: FnMut(&U) -> bool>(&self, pred: F) -> Option<usize> {
+        self.deref().iter().position(pred)
+    }
+    /// Returns an iterator over the sub-slices separated by elements matching
+    /// `is_delim`, each as a borrowed cow.
+    ///
+    /// Forwards to [`slice::split`](https://doc.rust-lang.org/core/primitive.slice.html#method.split).
+    /// The delimiters themselves are not included and no allocation occurs.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let buf: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 0, 3, 0, 0, 4]);
+    /// let parts: Vec<_> = buf.split_by(|b| *b == 0x00).collect();
+    /// assert_eq!(parts.len(), 4);
+    /// assert!(parts.iter().all(NoStdCow::is_borrowed));
+    /// assert_eq!(&*parts[0], &[1, 2]);
+    /// assert_eq!(&*parts[1], &[3]);
+    /// assert!(parts[2].is_empty());
+    /// assert_eq!(&*parts[3], &[4]);
+    /// ```
+    pub fn split_by<'s, F: FnMut(&U) -> bool>(&'s self, is_delim: F) -> impl Iterator<Item = NoStdCow<'s, T, [U]>> {
+        self.deref().split(is_delim).map(NoStdCow::Borrowed)
+    }
+    /// Empties the contents.
+    ///
+    /// Rather than clearing an owned buffer in place, the cow is switched to a
+    /// borrowed `'static` empty value, so this never allocates and works for any
+    /// owned type. Any owned value is dropped, including its capacity.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+    /// borrowed.clear();
+    /// assert!(borrowed.is_empty());
+    ///
+    /// let mut owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+    /// owned.clear();
+    /// assert!(owned.is_empty());
+    /// assert!(owned.is_borrowed());
+    /// ```
+    pub fn clear(&mut self) {
+        *self = Self::Borrowed(&[]);
+    }
+    /// Returns a borrowed cow over the whole slice.
+    ///
+    /// This cheaply downgrades an owned cow to a borrowed view of it.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+    /// let borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+    /// assert!(owned.as_slice_cow().is_borrowed());
+    /// assert!(borrowed.as_slice_cow().is_borrowed());
+    /// assert_eq!(owned.as_slice_cow(), [1u8, 2, 3]);
+    /// ```
+    pub fn as_slice_cow(&self) -> NoStdCow<'_, T, [U]> {
+        NoStdCow::Borrowed(self.deref())
+    }
+    /// Copies all `parts` one after another into `out` and returns a borrowed cow over
+    /// the written region.
+    ///
+    /// If `out` is too short, nothing is written and the total length required is
+    /// returned as the error instead.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let parts: [NoStdCow<'_, Vec<u8>, [u8]>; 2] = [NoStdCow::Borrowed(&[1, 2]), NoStdCow::Owned(vec![3])];
+    ///
+    /// let mut out = [0u8; 4];
+    /// let joined = NoStdCow::concat_into(&parts, &mut out).unwrap();
+    /// assert_eq!(joined, [1u8, 2, 3]);
+    /// assert!(joined.is_borrowed());
+    ///
+    /// let mut small = [0u8; 2];
+    /// assert_eq!(NoStdCow::concat_into(&parts, &mut small), Err(3));
+    /// ```
+    pub fn concat_into<'b>(parts: &[NoStdCow<'_, T, [U]>], out: &'b mut [U]) -> Result<NoStdCow<'b, T, [U]>, usize>
+    where
+        U: Copy,
+    {
+        let required = parts.iter().map(|part| part.len()).sum();
+        if out.len() < required {
+            return Err(required);
+        }
+        let mut written = 0;
+        for part in parts {
+            out[written..written + part.len()].copy_from_slice(part);
+            written += part.len();
+        }
+        Ok(NoStdCow::Borrowed(&out[..required]))
+    }
+    /// Returns a borrowed cow over `range` of the slice, or `None` if the range is out
+    /// of bounds.
+    ///
+    /// This is the non-panicking counterpart to slicing.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let cow: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+    /// assert_eq!(cow.get_range(1..3), Some(NoStdCow::Borrowed(&[2u8, 3][..])));
+    /// assert!(cow.get_range(1..3).unwrap().is_borrowed());
+    /// assert_eq!(cow.get_range(2..4), None);
+    /// ```
+    pub fn get_range(&self, range: Range<usize>) -> Option<NoStdCow<'_, T, [U]>> {
+        self.deref().get(range).map(NoStdCow::Borrowed)
+    }
+    /// Iterates over pairs of elements from this cow and `other`, stopping at the shorter one.
+    ///
+    /// Forwards to zipped [`slice::iter`](https://doc.rust-lang.org/core/primitive.slice.html#method.iter)s.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+    /// let owned: NoStdCow<'_, Vec<char>, [char]> = NoStdCow::Owned(vec!['a', 'b']);
+    /// let pairs: Vec<_> = borrowed.iter_zip(&owned).collect();
+    /// assert_eq!(pairs, [(&1, &'a'), (&2, &'b')]);
+    /// ```
+    pub fn iter_zip<'s, U2, T2: Borrow<[U2]>>(
+        &'s self,
+        other: &'s NoStdCow<'_, T2, [U2]>,
+    ) -> impl Iterator<Item = (&'s U, &'s U2)> {
+        self.deref().iter().zip(other.deref().iter())
+    }
+    /// Iterates over the contents in non-overlapping chunks of exactly `N` elements.
+    ///
+    /// A stable reimplementation of the unstable
+    /// [`slice::array_chunks`](https://doc.rust-lang.org/core/primitive.slice.html#method.array_chunks).
+    /// A trailing remainder shorter than `N` is skipped.
+    ///
+    /// # Panics
+    /// Panics if `N` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let even: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3, 4]);
+    /// let chunks: Vec<_> = even.array_chunks::<2>().collect();
+    /// assert_eq!(chunks, [&[1, 2], &[3, 4]]);
+    ///
+    /// let uneven: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3, 4, 5]);
+    /// let chunks: Vec<_> = uneven.array_chunks::<2>().collect();
+    /// assert_eq!(chunks, [&[1, 2], &[3, 4]]);
+    /// ```
+    pub fn array_chunks<const N: usize>(&self) -> impl Iterator<Item = &[U; N]> {
+        assert!(N != 0, "chunk size must be non-zero");
+        self.deref()
+            .chunks_exact(N)
+            .map(|chunk| chunk.try_into().expect("chunks_exact yields chunks of length N"))
+    }
+    /// Copies the contents into an array of exactly `N` elements.
+    ///
+    /// Succeeds only when the length is exactly `N`. Shorter or longer contents are
+    /// not padded or truncated; the original cow is handed back unchanged instead.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let exact: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3, 4]);
+    /// assert_eq!(exact.try_into_array::<4>(), Ok([1, 2, 3, 4]));
+    ///
+    /// let short: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+    /// let err = short.try_into_array::<4>().unwrap_err();
+    /// assert_eq!(err, NoStdCow::Owned(vec![1, 2, 3]));
+    /// assert!(err.is_owned());
+    ///
+    /// let long: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3, 4, 5]);
+    /// assert_eq!(long.try_into_array::<4>(), Err(NoStdCow::Borrowed(&[1u8, 2, 3, 4, 5][..])));
+    /// ```
+    pub fn try_into_array<const N: usize>(self) -> Result<[U; N], Self>
+    where
+        U: Copy,
+    {
+        match <[U; N]>::try_from(self.deref()) {
+            Ok(array) => Ok(array),
+            Err(_) => Err(self),
+        }
+    }
+    /// Splits off the first `N` elements as an array reference, returning the rest as
+    /// a borrowed cow.
+    ///
+    /// Returns `None` if the contents are shorter than `N`. Nothing is copied, which
+    /// suits parsing a fixed-size header followed by a variable-length payload.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let packet: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![0xCA, 0xFE, 1, 2, 3]);
+    /// let (magic, payload) = packet.split_array::<2>().unwrap();
+    /// assert_eq!(magic, &[0xCA, 0xFE]);
+    /// assert_eq!(payload, [1u8, 2, 3]);
+    /// assert!(payload.is_borrowed());
+    ///
+    /// let exact: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2]);
+    /// let (head, tail) = exact.split_array::<2>().unwrap();
+    /// assert_eq!(head, &[1, 2]);
+    /// assert!(tail.is_empty());
+    ///
+    /// let short: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1]);
+    /// assert!(short.split_array::<2>().is_none());
+    /// ```
+    pub fn split_array<const N: usize>(&self) -> Option<SplitArray<'_, T, U, N>> {
+        let slice = self.deref();
+        if slice.len() < N {
+            return None;
+        }
+        let (head, tail) = slice.split_at(N);
+        Some((head.try_into().expect("head has length N"), NoStdCow::Borrowed(tail)))
+    }
+    /// Binary searches the sorted slice for `x`.
+    ///
+    /// Forwards to [`slice::binary_search`](https://doc.rust-lang.org/core/primitive.slice.html#method.binary_search):
+    /// returns `Ok` with the index of a matching element, or `Err` with the index
+    /// where `x` could be inserted to keep the slice sorted. The result is
+    /// meaningless if the slice is not sorted.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 3, 5, 7]);
+    /// assert_eq!(borrowed.binary_search(&5), Ok(2));
+    /// assert_eq!(borrowed.binary_search(&4), Err(2));
+    ///
+    /// let owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 3, 5, 7]);
+    /// assert_eq!(owned.binary_search(&1), Ok(0));
+    /// assert_eq!(owned.binary_search(&9), Err(4));
+    /// ```
+    pub fn binary_search(&self, x: &U) -> Result<usize, usize>
+    where
+        U: Ord,
+    {
+        self.deref().binary_search(x)
+    }
+    /// Returns true if both cows have the same length and each pair of elements is
+    /// equal, even when the element types differ.
+    ///
+    /// Elements are compared with `U: PartialEq<U2>`, so differently-typed buffers
+    /// that are logically equal can be compared directly. Contents of different
+    /// lengths are never equal.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let owned: NoStdCow<'_, Vec<String>, [String]> = NoStdCow::Owned(vec![String::from("a"), String::from("b")]);
+    /// let borrowed: NoStdCow<'_, Vec<&str>, [&str]> = NoStdCow::Borrowed(&["a", "b"]);
+    /// assert!(owned.elements_eq(&borrowed));
+    ///
+    /// let longer: NoStdCow<'_, Vec<&str>, [&str]> = NoStdCow::Borrowed(&["a", "b", "c"]);
+    /// assert!(!owned.elements_eq(&longer));
+    /// let different: NoStdCow<'_, Vec<&str>, [&str]> = NoStdCow::Borrowed(&["a", "c"]);
+    /// assert!(!owned.elements_eq(&different));
+    /// ```
+    pub fn elements_eq<U2, T2: Borrow<[U2]>>(&self, other: &NoStdCow<'_, T2, [U2]>) -> bool
+    where
+        U: PartialEq<U2>,
+    {
+        self.deref() == other.deref()
+    }
+    /// Folds the elements into an accumulator, stopping at the first error.
+    ///
+    /// Forwards to [`Iterator::try_fold`] over the contents and never allocates.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let sum = |acc: u8, x: &u8| acc.checked_add(*x).ok_or("overflow");
+    ///
+    /// let small: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+    /// assert_eq!(small.try_fold(0, sum), Ok(6));
+    ///
+    /// let large: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![200, 100, 1]);
+    /// assert_eq!(large.try_fold(0, sum), Err("overflow"));
+    /// ```
+    pub fn try_fold<Acc, E, F: FnMut(Acc, &U) -> Result<Acc, E>>(&self, init: Acc, f: F) -> Result<Acc, E> {
+        self.deref().iter().try_fold(init, f)
+    }
+    /// Returns an iterator over the maximal runs of consecutive elements with equal
+    /// keys, each as a borrowed cow.
+    ///
+    /// `key` is computed exactly once per element and a new run starts whenever it
+    /// differs from the key of the run's first element. The key that ends a run is
+    /// carried over as the key of the next one. All runs are borrowed and no
+    /// allocation occurs.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let bytes: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 1, 2, 3, 3, 3, 1]);
+    /// let runs: Vec<_> = bytes.group_runs(|b| *b).collect();
+    /// assert_eq!(runs, [&[1u8, 1][..], &[2], &[3, 3, 3], &[1]].map(NoStdCow::Borrowed));
+    /// assert!(runs.iter().all(NoStdCow::is_borrowed));
+    ///
+    /// let parity: Vec<_> = bytes.group_runs(|b| b % 2).map(|run| run.len()).collect();
+    /// assert_eq!(parity, [2, 1, 4]);
+    ///
+    /// let empty: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[]);
+    /// assert_eq!(empty.group_runs(|b| *b).count(), 0);
+    ///
+    /// let mut calls = 0;
+    /// let count = bytes.group_runs(|b| { calls += 1; *b }).count();
+    /// assert_eq!(count, 4);
+    /// assert_eq!(calls, bytes.len());
+    /// ```
+    pub fn group_runs<K: PartialEq, F: FnMut(&U) -> K>(&self, mut key: F) -> impl Iterator<Item = NoStdCow<'_, T, [U]>> {
+        let mut rest = self.deref();
+        let mut next_key = None;
+        core::iter::from_fn(move || {
+            let (first, tail) = rest.split_first()?;
+            let run_key = next_key.take().unwrap_or_else(|| key(first));
+            let mut len = 1;
+            for x in tail {
+                let k = key(x);
+                if k != run_key {
+                    next_key = Some(k);
+                    break;
+                }
+                len += 1;
+            }
+            let (run, remainder) = rest.split_at(len);
+            rest = remainder;
+            Some(NoStdCow::Borrowed(run))
+        })
+    }
+    /// Concatenates slice cows, borrowing whenever no copy is needed.
+    ///
+    /// - With no parts, an empty [`NoStdCow::Borrowed`] is returned.
+    /// - With exactly one part, a [`NoStdCow::Borrowed`] reborrow of it is returned,
+    ///   whichever variant it is.
+    /// - With several parts, their elements are cloned into an owned `T`.
+    ///
+    /// See [`NoStdCow::concat_into`] for concatenating into a caller-supplied buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let none: [NoStdCow<'_, Vec<u8>, [u8]>; 0] = [];
+    /// assert!(NoStdCow::<'_, Vec<u8>, [u8]>::concat_cows(&none).is_empty());
+    ///
+    /// let one: [NoStdCow<'_, Vec<u8>, [u8]>; 1] = [NoStdCow::Owned(vec![1, 2])];
+    /// let single = NoStdCow::<'_, Vec<u8>, [u8]>::concat_cows(&one);
+    /// assert!(single.is_borrowed());
+    /// assert_eq!(single.as_ptr(), one[0].as_ptr());
+    ///
+    /// let many: [NoStdCow<'_, Vec<u8>, [u8]>; 2] = [NoStdCow::Borrowed(&[1, 2]), NoStdCow::Owned(vec![3])];
+    /// let joined = NoStdCow::<'_, Vec<u8>, [u8]>::concat_cows(&many);
+    /// assert_eq!(joined, NoStdCow::Owned(vec![1, 2, 3]));
+    /// assert!(joined.is_owned());
+    /// ```
+    pub fn concat_cows<'p>(parts: &'p [NoStdCow<'_, T, [U]>]) -> NoStdCow<'p, T, [U]>
+    where
+        U: Clone,
+        T: FromIterator<U>,
+    {
+        match parts {
+            [] => NoStdCow::Borrowed(&[]),
+            [single] => NoStdCow::Borrowed(single.deref()),
+            _ => NoStdCow::Owned(parts.iter().flat_map(|part| part.iter().cloned()).collect()),
+        }
+    }
+}
+
+impl<T: Borrow<[u8]>> NoStdCow<'_, T, [u8]> {
+    /// Returns true if every byte is ASCII.
+    ///
+    /// Forwards to [`slice::is_ascii`](https://doc.rust-lang.org/core/primitive.slice.html#method.is_ascii).
+    /// An empty slice is ASCII.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let ascii: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(b"GET /");
+    /// assert!(ascii.is_ascii());
+    /// let non_ascii: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![b'a', 0xFF]);
+    /// assert!(!non_ascii.is_ascii());
+    /// ```
+    pub fn is_ascii(&self) -> bool {
+        self.deref().is_ascii()
+    }
+}
+
+/// Compares the contents of a slice cow against an array.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2, 3]);
+/// let owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2, 3]);
+/// assert_eq!(borrowed, [1u8, 2, 3]);
+/// assert_eq!([1u8, 2, 3], owned);
+/// assert_ne!(owned, [1u8, 2]);
+/// ```
+impl<U: PartialEq, T: Borrow<[U]>, const N: usize> PartialEq<[U; N]> for NoStdCow<'_, T, [U]> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.deref() == &other[..]
+    }
+}
+
+impl<U: PartialEq, T: Borrow<[U]>, const N: usize> PartialEq<NoStdCow<'_, T, [U]>> for [U; N] {
+    fn eq(&self, other: &NoStdCow<'_, T, [U]>) -> bool {
+        self[..] == *other.deref()
+    }
+}
+
+/// Compares the contents of a slice cow against a slice.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let borrowed: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(&[1, 2]);
+/// let owned: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(vec![1, 2]);
+/// assert_eq!(borrowed, &[1u8, 2][..]);
+/// assert_eq!(&[1u8, 2][..], owned);
+/// assert_ne!(owned, &[2u8, 1][..]);
+/// ```
+impl<U: PartialEq, T: Borrow<[U]>> PartialEq<&[U]> for NoStdCow<'_, T, [U]> {
+    fn eq(&self, other: &&[U]) -> bool {
+        self.deref() == *other
+    }
+}
+
+impl<U: PartialEq, T: Borrow<[U]>> PartialEq<NoStdCow<'_, T, [U]>> for &[U] {
+    fn eq(&self, other: &NoStdCow<'_, T, [U]>) -> bool {
+        *self == other.deref()
+    }
+}
+
+/// Borrows an array as a slice cow.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let cow: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::from(&[1u8, 2, 3]);
+/// assert!(cow.is_borrowed());
+/// assert_eq!(cow, [1u8, 2, 3]);
+/// ```
+impl<'a, U, T: Borrow<[U]>, const N: usize> From<&'a [U; N]> for NoStdCow<'a, T, [U]> {
+    fn from(value: &'a [U; N]) -> Self {
+        Self::Borrowed(value)
+    }
+}
@@ -0,0 +1,661 @@
+use crate::NoStdCow;
+use core::borrow::{Borrow, BorrowMut};
+use core::ops::{Deref, Range};
+use core::str::{FromStr, Utf8Error};
+
+impl<T: Borrow<str>> NoStdCow<'_, T, str> {
+    /// Checks that the string is an ASCII case-insensitive match for `other`.
+    ///
+    /// Forwards to [`str::eq_ignore_ascii_case`], so no lowercased copy is allocated.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("Content-Type");
+    /// assert!(borrowed.eq_ignore_ascii_case("content-type"));
+    ///
+    /// let owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("Content-Type"));
+    /// assert!(owned.eq_ignore_ascii_case("content-type"));
+    /// assert!(!owned.eq_ignore_ascii_case("content-length"));
+    /// ```
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.deref().eq_ignore_ascii_case(other)
+    }
+    /// Returns an iterator over the lines of the string, each as a borrowed cow.
+    ///
+    /// Forwards to [`str::lines`]. All yielded cows are [`NoStdCow::Borrowed`]. The std
+    /// method, which yields `&str` lines, is still reachable through deref.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let trailing: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("a=1\nb=2\n"));
+    /// let lines: Vec<_> = trailing.lines_cow().collect();
+    /// assert_eq!(lines, [NoStdCow::Borrowed("a=1"), NoStdCow::Borrowed("b=2")]);
+    /// assert!(lines.iter().all(|line| line.is_borrowed()));
+    ///
+    /// let no_trailing: NoStdCow<'_, String, str> = NoStdCow::Borrowed("a=1\r\nb=2");
+    /// let lines: Vec<_> = no_trailing.lines_cow().collect();
+    /// assert_eq!(lines, [NoStdCow::Borrowed("a=1"), NoStdCow::Borrowed("b=2")]);
+    ///
+    /// let plain: Vec<&str> = no_trailing.lines().collect();
+    /// assert_eq!(plain, ["a=1", "b=2"]);
+    /// ```
+    pub fn lines_cow(&self) -> impl Iterator<Item = NoStdCow<'_, T, str>> {
+        self.deref().lines().map(NoStdCow::Borrowed)
+    }
+    /// Returns the string with ASCII letters uppercased, borrowing when nothing changes.
+    ///
+    /// If the string contains no lowercase ASCII letters a [`NoStdCow::Borrowed`] view
+    /// of the contents is returned without allocating. Otherwise an owned `T` is built
+    /// from the contents and uppercased in place.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let upper: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("HELLO"));
+    /// assert!(upper.to_ascii_uppercase_cow().is_borrowed());
+    /// assert_eq!(upper.to_ascii_uppercase_cow(), NoStdCow::Borrowed("HELLO"));
+    ///
+    /// let mixed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("Hello");
+    /// assert!(mixed.to_ascii_uppercase_cow().is_owned());
+    /// assert_eq!(mixed.to_ascii_uppercase_cow(), NoStdCow::Owned(String::from("HELLO")));
+    /// ```
+    pub fn to_ascii_uppercase_cow(&self) -> NoStdCow<'_, T, str>
+    where
+        T: for<'s> From<&'s str> + BorrowMut<str>,
+    {
+        let s = self.deref();
+        if !s.bytes().any(|b| b.is_ascii_lowercase()) {
+            return NoStdCow::Borrowed(s);
+        }
+        let mut owned = T::from(s);
+        owned.borrow_mut().make_ascii_uppercase();
+        NoStdCow::Owned(owned)
+    }
+    /// Returns the string with ASCII letters lowercased, borrowing when nothing changes.
+    ///
+    /// If the string contains no uppercase ASCII letters a [`NoStdCow::Borrowed`] view
+    /// of the contents is returned without allocating. Otherwise an owned `T` is built
+    /// from the contents and lowercased in place.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let lower: NoStdCow<'_, String, str> = NoStdCow::Borrowed("hello");
+    /// assert!(lower.to_ascii_lowercase_cow().is_borrowed());
+    /// assert_eq!(lower.to_ascii_lowercase_cow(), NoStdCow::Borrowed("hello"));
+    ///
+    /// let mixed: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("Hello"));
+    /// assert!(mixed.to_ascii_lowercase_cow().is_owned());
+    /// assert_eq!(mixed.to_ascii_lowercase_cow(), NoStdCow::Owned(String::from("hello")));
+    /// ```
+    pub fn to_ascii_lowercase_cow(&self) -> NoStdCow<'_, T, str>
+    where
+        T: for<'s> From<&'s str> + BorrowMut<str>,
+    {
+        let s = self.deref();
+        if !s.bytes().any(|b| b.is_ascii_uppercase()) {
+            return NoStdCow::Borrowed(s);
+        }
+        let mut owned = T::from(s);
+        owned.borrow_mut().make_ascii_lowercase();
+        NoStdCow::Owned(owned)
+    }
+    /// Empties the contents.
+    ///
+    /// Rather than clearing an owned buffer in place, the cow is switched to a
+    /// borrowed `'static` empty value, so this never allocates and works for any
+    /// owned type. Any owned value is dropped, including its capacity.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("hello");
+    /// borrowed.clear();
+    /// assert!(borrowed.is_empty());
+    ///
+    /// let mut owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("hello"));
+    /// owned.clear();
+    /// assert!(owned.is_empty());
+    /// assert!(owned.is_borrowed());
+    /// ```
+    pub fn clear(&mut self) {
+        *self = Self::Borrowed("");
+    }
+    /// Returns the number of [`char`]s in the string, as opposed to its length in bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let ascii: NoStdCow<'_, String, str> = NoStdCow::Borrowed("hello");
+    /// assert_eq!(ascii.char_count(), 5);
+    ///
+    /// let multi_byte: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("héllo"));
+    /// assert_eq!(multi_byte.char_count(), 5);
+    /// assert_eq!(multi_byte.len(), 6);
+    /// ```
+    pub fn char_count(&self) -> usize {
+        self.deref().chars().count()
+    }
+    /// Splits the string on the first occurrence of `delim`, returning both halves
+    /// as borrowed cows.
+    ///
+    /// Forwards to [`str::split_once`]. Returns `None` if `delim` is absent. The std
+    /// method, which accepts any pattern and returns `&str` halves, is still
+    /// reachable through deref.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let pair: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("key=value=x"));
+    /// assert_eq!(pair.split_once_cow('='), Some((NoStdCow::Borrowed("key"), NoStdCow::Borrowed("value=x"))));
+    /// let (key, value) = pair.split_once_cow('=').unwrap();
+    /// assert!(key.is_borrowed() && value.is_borrowed());
+    ///
+    /// let edge: NoStdCow<'_, String, str> = NoStdCow::Borrowed("key=");
+    /// assert_eq!(edge.split_once_cow('='), Some((NoStdCow::Borrowed("key"), NoStdCow::Borrowed(""))));
+    ///
+    /// let absent: NoStdCow<'_, String, str> = NoStdCow::Borrowed("key");
+    /// assert_eq!(absent.split_once_cow('='), None);
+    ///
+    /// let header: NoStdCow<'_, String, str> = NoStdCow::Borrowed("Host: example.com");
+    /// assert_eq!(header.split_once(": "), Some(("Host", "example.com")));
+    /// ```
+    pub fn split_once_cow(&self, delim: char) -> Option<(NoStdCow<'_, T, str>, NoStdCow<'_, T, str>)> {
+        let (left, right) = self.deref().split_once(delim)?;
+        Some((NoStdCow::Borrowed(left), NoStdCow::Borrowed(right)))
+    }
+    /// Returns true if the bytes of the string equal the contents of a byte cow.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let text: NoStdCow<'_, String, str> = NoStdCow::Borrowed("GET");
+    /// let same: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(b"GET".to_vec());
+    /// let different: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(b"PUT");
+    /// assert!(text.eq_bytes(&same));
+    /// assert!(!text.eq_bytes(&different));
+    /// ```
+    pub fn eq_bytes<T2: Borrow<[u8]>>(&self, other: &NoStdCow<'_, T2, [u8]>) -> bool {
+        self.deref().as_bytes() == other.deref()
+    }
+    /// Returns an iterator over the substrings separated by `delim`, starting from
+    /// the end, each as a borrowed cow.
+    ///
+    /// Forwards to [`str::rsplit`]. The std method, which accepts any pattern and
+    /// yields `&str` pieces, is still reachable through deref.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let path: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("a.b.c"));
+    /// let reverse: Vec<_> = path.rsplit_cow('.').collect();
+    /// let mut forward: Vec<_> = path.split('.').map(NoStdCow::Borrowed).collect();
+    /// forward.reverse();
+    /// assert_eq!(reverse, forward);
+    /// assert_eq!(reverse[0], NoStdCow::Borrowed("c"));
+    /// assert!(reverse.iter().all(|part| part.is_borrowed()));
+    ///
+    /// let words: Vec<&str> = path.rsplit(|c: char| c == '.').collect();
+    /// assert_eq!(words, ["c", "b", "a"]);
+    /// ```
+    pub fn rsplit_cow(&self, delim: char) -> impl Iterator<Item = NoStdCow<'_, T, str>> {
+        self.deref().rsplit(delim).map(NoStdCow::Borrowed)
+    }
+    /// Returns an iterator over at most `n` substrings separated by `delim`, starting
+    /// from the end, each as a borrowed cow. The last item holds the unsplit remainder.
+    ///
+    /// Forwards to [`str::rsplitn`]. The std method, which accepts any pattern, is
+    /// still reachable through deref.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let file: NoStdCow<'_, String, str> = NoStdCow::Borrowed("archive.tar.gz");
+    /// let parts: Vec<_> = file.rsplitn_cow(2, '.').collect();
+    /// assert_eq!(parts, [NoStdCow::Borrowed("gz"), NoStdCow::Borrowed("archive.tar")]);
+    /// assert!(parts.iter().all(|part| part.is_borrowed()));
+    ///
+    /// let parts: Vec<&str> = file.rsplitn(2, ".tar").collect();
+    /// assert_eq!(parts, [".gz", "archive"]);
+    /// ```
+    pub fn rsplitn_cow(&self, n: usize, delim: char) -> impl Iterator<Item = NoStdCow<'_, T, str>> {
+        self.deref().rsplitn(n, delim).map(NoStdCow::Borrowed)
+    }
+    /// Returns the remainder after removing `prefix` as a borrowed cow, or `None` if
+    /// the string doesn't start with `prefix`.
+    ///
+    /// Forwards to [`str::strip_prefix`]. The std method, which accepts any pattern
+    /// and returns a `&str`, is still reachable through deref.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let path: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("/api/users"));
+    /// assert_eq!(path.strip_prefix_cow("/api"), Some(NoStdCow::Borrowed("/users")));
+    /// assert!(path.strip_prefix_cow("/api").unwrap().is_borrowed());
+    /// assert_eq!(path.strip_prefix_cow("/web"), None);
+    ///
+    /// assert_eq!(path.strip_prefix('/'), Some("api/users"));
+    /// ```
+    pub fn strip_prefix_cow(&self, prefix: &str) -> Option<NoStdCow<'_, T, str>> {
+        self.deref().strip_prefix(prefix).map(NoStdCow::Borrowed)
+    }
+    /// Returns the remainder after removing `suffix` as a borrowed cow, or `None` if
+    /// the string doesn't end with `suffix`.
+    ///
+    /// Forwards to [`str::strip_suffix`]. The std method, which accepts any pattern
+    /// and returns a `&str`, is still reachable through deref.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let file: NoStdCow<'_, String, str> = NoStdCow::Borrowed("index.html");
+    /// assert_eq!(file.strip_suffix_cow(".html"), Some(NoStdCow::Borrowed("index")));
+    /// assert!(file.strip_suffix_cow(".html").unwrap().is_borrowed());
+    /// assert_eq!(file.strip_suffix_cow(".css"), None);
+    ///
+    /// assert_eq!(file.strip_suffix(|c: char| c == 'l'), Some("index.htm"));
+    /// ```
+    pub fn strip_suffix_cow(&self, suffix: &str) -> Option<NoStdCow<'_, T, str>> {
+        self.deref().strip_suffix(suffix).map(NoStdCow::Borrowed)
+    }
+    /// Returns a borrowed cow over the whole string.
+    ///
+    /// This cheaply downgrades an owned cow to a borrowed view of it.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("hello"));
+    /// let borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("hello");
+    /// assert!(owned.as_slice_cow().is_borrowed());
+    /// assert!(borrowed.as_slice_cow().is_borrowed());
+    /// assert_eq!(owned.as_slice_cow(), NoStdCow::Borrowed("hello"));
+    /// ```
+    pub fn as_slice_cow(&self) -> NoStdCow<'_, T, str> {
+        NoStdCow::Borrowed(self.deref())
+    }
+    /// Parses the string into an owned [`RefCow`](crate::RefCow) of another type.
+    ///
+    /// Forwards to [`str::parse`]. The result is always [`NoStdCow::Owned`]. Use
+    /// `str::parse` through deref to get the plain value instead.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::{NoStdCow, RefCow};
+    ///
+    /// let number: NoStdCow<'_, String, str> = NoStdCow::Borrowed("42");
+    /// assert_eq!(number.parse_cow::<i32>(), Ok(RefCow::Owned(42)));
+    /// let plain: i32 = number.parse().unwrap();
+    /// assert_eq!(plain, 42);
+    ///
+    /// let invalid: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("forty-two"));
+    /// assert!(invalid.parse_cow::<i32>().is_err());
+    /// ```
+    pub fn parse_cow<F: FromStr>(&self) -> Result<NoStdCow<'static, F, F>, F::Err> {
+        self.deref().parse().map(NoStdCow::Owned)
+    }
+    /// Returns the closest [`char`] boundary at or before `index`.
+    ///
+    /// Indices past the end are clamped to the length of the string. This is a stable
+    /// reimplementation of the `str::floor_char_boundary` logic.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let ascii: NoStdCow<'_, String, str> = NoStdCow::Borrowed("abc");
+    /// assert_eq!(ascii.floor_char_boundary(1), 1);
+    /// assert_eq!(ascii.floor_char_boundary(10), 3);
+    ///
+    /// // 'é' occupies bytes 1 and 2.
+    /// let multi_byte: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("aéb"));
+    /// assert_eq!(multi_byte.floor_char_boundary(2), 1);
+    /// assert_eq!(multi_byte.floor_char_boundary(3), 3);
+    /// ```
+    pub fn floor_char_boundary(&self, index: usize) -> usize {
+        let s = self.deref();
+        if index >= s.len() {
+            return s.len();
+        }
+        let mut index = index;
+        while !s.is_char_boundary(index) {
+            index -= 1;
+        }
+        index
+    }
+    /// Returns the closest [`char`] boundary at or after `index`.
+    ///
+    /// Indices past the end are clamped to the length of the string. This is a stable
+    /// reimplementation of the `str::ceil_char_boundary` logic.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let ascii: NoStdCow<'_, String, str> = NoStdCow::Borrowed("abc");
+    /// assert_eq!(ascii.ceil_char_boundary(1), 1);
+    /// assert_eq!(ascii.ceil_char_boundary(10), 3);
+    ///
+    /// // '🦀' occupies bytes 1 to 4.
+    /// let multi_byte: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("a🦀b"));
+    /// assert_eq!(multi_byte.ceil_char_boundary(2), 5);
+    /// assert_eq!(multi_byte.ceil_char_boundary(1), 1);
+    /// ```
+    pub fn ceil_char_boundary(&self, index: usize) -> usize {
+        let s = self.deref();
+        if index >= s.len() {
+            return s.len();
+        }
+        let mut index = index;
+        while !s.is_char_boundary(index) {
+            index += 1;
+        }
+        index
+    }
+    /// Returns an iterator over the whitespace-separated words of the string, each as
+    /// a borrowed cow.
+    ///
+    /// Forwards to [`str::split_whitespace`], so leading, trailing and repeated
+    /// whitespace never produce empty words. The std method, which yields `&str`
+    /// words, is still reachable through deref.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let config: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("  mode \t fast\n\n retries 3 "));
+    /// let words: Vec<_> = config.split_whitespace_cow().collect();
+    /// assert_eq!(words, ["mode", "fast", "retries", "3"].map(NoStdCow::Borrowed));
+    /// assert!(words.iter().all(NoStdCow::is_borrowed));
+    ///
+    /// let plain: Vec<&str> = config.split_whitespace().collect();
+    /// assert_eq!(plain, ["mode", "fast", "retries", "3"]);
+    /// ```
+    pub fn split_whitespace_cow(&self) -> impl Iterator<Item = NoStdCow<'_, T, str>> {
+        self.deref().split_whitespace().map(NoStdCow::Borrowed)
+    }
+    /// Returns a borrowed cow over the byte `range` of the string, or `None` if the
+    /// range is out of bounds or doesn't lie on [`char`] boundaries.
+    ///
+    /// This is the non-panicking counterpart to slicing, forwarding to [`str::get`].
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let cow: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("héllo"));
+    /// assert_eq!(cow.get_range(3..6), Some(NoStdCow::Borrowed("llo")));
+    /// assert!(cow.get_range(3..6).unwrap().is_borrowed());
+    /// assert_eq!(cow.get_range(3..7), None);
+    /// // Byte 2 is inside 'é'.
+    /// assert_eq!(cow.get_range(0..2), None);
+    /// ```
+    pub fn get_range(&self, range: Range<usize>) -> Option<NoStdCow<'_, T, str>> {
+        self.deref().get(range).map(NoStdCow::Borrowed)
+    }
+    /// Returns a lazy iterator over the non-empty tokens separated by characters
+    /// matching `is_delim`, each as a borrowed cow.
+    ///
+    /// Tokens are produced on demand and the iterator itself never allocates. Runs
+    /// of delimiters, and delimiters at either end, never produce empty tokens.
+    /// Ownership is opt-in: only the tokens the caller decides to keep need to be
+    /// copied into an owned value, and discarded tokens cost nothing. With the
+    /// `alloc` feature, `into_owned_cow` performs that copy for `String` cows.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let stream: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("let x = 42; let y = 7;"));
+    /// let kept: Vec<NoStdCow<'static, String, str>> = stream
+    ///     .tokens(|c| c == ' ' || c == ';')
+    ///     .inspect(|token| assert!(token.is_borrowed()))
+    ///     .filter(|token| token.parse::<u32>().is_ok())
+    ///     .map(|token| NoStdCow::Owned(String::from(&*token)))
+    ///     .collect();
+    /// // The kept tokens no longer borrow from the source.
+    /// drop(stream);
+    /// assert_eq!(kept, [NoStdCow::Borrowed("42"), NoStdCow::Borrowed("7")]);
+    /// assert!(kept.iter().all(NoStdCow::is_owned));
+    /// ```
+    pub fn tokens<F: FnMut(char) -> bool>(&self, is_delim: F) -> impl Iterator<Item = NoStdCow<'_, T, str>> {
+        self.deref()
+            .split(is_delim)
+            .filter(|token| !token.is_empty())
+            .map(NoStdCow::Borrowed)
+    }
+    /// Returns true if every character of the string is ASCII.
+    ///
+    /// Forwards to [`str::is_ascii`]. An empty string is ASCII.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let ascii: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("Host: a.b"));
+    /// assert!(ascii.is_ascii());
+    /// let non_ascii: NoStdCow<'_, String, str> = NoStdCow::Borrowed("héllo");
+    /// assert!(!non_ascii.is_ascii());
+    /// ```
+    pub fn is_ascii(&self) -> bool {
+        self.deref().is_ascii()
+    }
+    /// Returns a borrowed view of the string if it is entirely ASCII, or `None` if
+    /// it contains any non-ASCII character.
+    ///
+    /// This validates without copying, for protocols that require ASCII.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let ascii: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("GET"));
+    /// let view = ascii.as_ascii().unwrap();
+    /// assert_eq!(view, NoStdCow::Borrowed("GET"));
+    /// assert!(view.is_borrowed());
+    ///
+    /// let non_ascii: NoStdCow<'_, String, str> = NoStdCow::Borrowed("GÉT");
+    /// assert_eq!(non_ascii.as_ascii(), None);
+    /// ```
+    pub fn as_ascii(&self) -> Option<NoStdCow<'_, T, str>> {
+        let s = self.deref();
+        if !s.is_ascii() {
+            return None;
+        }
+        Some(NoStdCow::Borrowed(s))
+    }
+    /// Returns the string with each run of whitespace replaced by a single space,
+    /// borrowing when it is already normalized.
+    ///
+    /// If every whitespace character is already a lone `' '`, a
+    /// [`NoStdCow::Borrowed`] view of the contents is returned without allocating.
+    /// Otherwise an owned `T` is collected from the normalized characters. Leading
+    /// and trailing whitespace is collapsed but not removed.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let clean: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("a b c"));
+    /// assert_eq!(clean.collapse_whitespace(), NoStdCow::Borrowed("a b c"));
+    /// assert!(clean.collapse_whitespace().is_borrowed());
+    ///
+    /// let messy: NoStdCow<'_, String, str> = NoStdCow::Borrowed("a  b\t\nc\td ");
+    /// assert_eq!(messy.collapse_whitespace(), NoStdCow::Borrowed("a b c d "));
+    /// assert!(messy.collapse_whitespace().is_owned());
+    /// ```
+    pub fn collapse_whitespace(&self) -> NoStdCow<'_, T, str>
+    where
+        T: FromIterator<char>,
+    {
+        let s = self.deref();
+        let mut prev_ws = false;
+        let normalized = s.chars().all(|c| {
+            let ws = c.is_whitespace();
+            let ok = !ws || (c == ' ' && !prev_ws);
+            prev_ws = ws;
+            ok
+        });
+        if normalized {
+            return NoStdCow::Borrowed(s);
+        }
+        let mut prev_ws = false;
+        NoStdCow::Owned(
+            s.chars()
+                .filter_map(|c| {
+                    let ws = c.is_whitespace();
+                    let out = match (ws, prev_ws) {
+                        (true, true) => None,
+                        (true, false) => Some(' '),
+                        (false, _) => Some(c),
+                    };
+                    prev_ws = ws;
+                    out
+                })
+                .collect(),
+        )
+    }
+    /// Concatenates string cows, borrowing whenever no copy is needed.
+    ///
+    /// - With no parts, an empty [`NoStdCow::Borrowed`] is returned.
+    /// - With exactly one part, a [`NoStdCow::Borrowed`] reborrow of it is returned,
+    ///   whichever variant it is.
+    /// - With several parts, they are collected into an owned `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let none: [NoStdCow<'_, String, str>; 0] = [];
+    /// assert_eq!(NoStdCow::<'_, String, str>::concat_cows(&none), NoStdCow::Borrowed(""));
+    ///
+    /// let one: [NoStdCow<'_, String, str>; 1] = [NoStdCow::Owned(String::from("a"))];
+    /// let single = NoStdCow::<'_, String, str>::concat_cows(&one);
+    /// assert!(single.is_borrowed());
+    /// assert_eq!(single.as_ptr(), one[0].as_ptr());
+    ///
+    /// let many: [NoStdCow<'_, String, str>; 3] = [NoStdCow::Borrowed("a"), NoStdCow::Owned(String::from("b")), NoStdCow::Borrowed("c")];
+    /// let joined = NoStdCow::<'_, String, str>::concat_cows(&many);
+    /// assert_eq!(joined, NoStdCow::Owned(String::from("abc")));
+    /// assert!(joined.is_owned());
+    /// ```
+    pub fn concat_cows<'p>(parts: &'p [NoStdCow<'_, T, str>]) -> NoStdCow<'p, T, str>
+    where
+        T: for<'s> FromIterator<&'s str>,
+    {
+        match parts {
+            [] => NoStdCow::Borrowed(""),
+            [single] => NoStdCow::Borrowed(single.deref()),
+            _ => NoStdCow::Owned(parts.iter().map(|part| part.deref()).collect()),
+        }
+    }
+}
+
+impl<'a, T: Borrow<str>> NoStdCow<'a, T, str> {
+    /// Validates a mutable byte buffer as UTF-8 and returns a borrowed str cow over it.
+    ///
+    /// This is meant for buffers that were decoded in place. The mutability of the
+    /// buffer is discarded: the returned cow only holds a shared borrow, but that
+    /// borrow still lasts for the whole lifetime `'a`. Invalid UTF-8 is reported with
+    /// [`Utf8Error`] from [`core::str::from_utf8`].
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let mut buf = *b"hello";
+    /// buf[0] = b'j';
+    /// let cow: NoStdCow<'_, String, str> = NoStdCow::from_utf8_validated_mut(&mut buf).unwrap();
+    /// assert_eq!(cow, NoStdCow::Borrowed("jello"));
+    /// assert!(cow.is_borrowed());
+    ///
+    /// let mut invalid = *b"hi\xFFthere";
+    /// let err = NoStdCow::<'_, String, str>::from_utf8_validated_mut(&mut invalid).unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 2);
+    /// ```
+    pub fn from_utf8_validated_mut(bytes: &'a mut [u8]) -> Result<Self, Utf8Error> {
+        core::str::from_utf8(bytes).map(Self::Borrowed)
+    }
+}
+
+/// Views the string contents as bytes, for byte-oriented APIs.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// fn byte_len<A: AsRef<[u8]>>(data: A) -> usize {
+///     data.as_ref().len()
+/// }
+/// let borrowed: NoStdCow<'_, String, str> = NoStdCow::Borrowed("héllo");
+/// let owned: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("héllo"));
+/// assert_eq!(byte_len(&borrowed), 6);
+/// assert_eq!(byte_len(owned), 6);
+/// ```
+impl<T: Borrow<str>> AsRef<[u8]> for NoStdCow<'_, T, str> {
+    fn as_ref(&self) -> &[u8] {
+        self.deref().as_bytes()
+    }
+}
+
+/// Views the contents as a `str`, alongside the byte view.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let cow: NoStdCow<'_, String, str> = NoStdCow::Owned(String::from("héllo"));
+/// let text: &str = cow.as_ref();
+/// assert_eq!(text, "héllo");
+/// assert_eq!(AsRef::<str>::as_ref(&cow), "héllo");
+/// assert_eq!(AsRef::<[u8]>::as_ref(&cow), "héllo".as_bytes());
+/// ```
+impl<T: Borrow<str>> AsRef<str> for NoStdCow<'_, T, str> {
+    fn as_ref(&self) -> &str {
+        self.deref()
+    }
+}
+
+/// Validates a byte cow as UTF-8 and borrows it as a str cow, without copying.
+///
+/// The result is always [`NoStdCow::Borrowed`], pointing into the byte cow's
+/// contents, so the owned types of the two cows can differ. Invalid UTF-8 is
+/// reported with [`Utf8Error`]. With the `alloc` feature, a `Vec`-backed byte cow
+/// can also be converted by value, keeping an owned buffer owned.
+///
+/// # Example
+/// ```
+/// use nostd_cow::NoStdCow;
+///
+/// let bytes: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(b"hello".to_vec());
+/// let text = NoStdCow::<'_, String, str>::try_from(&bytes).unwrap();
+/// assert_eq!(text, NoStdCow::Borrowed("hello"));
+/// assert!(text.is_borrowed());
+/// assert_eq!(text.as_ptr(), bytes.as_ptr());
+///
+/// let invalid: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(b"hi\xFF");
+/// let err = NoStdCow::<'_, String, str>::try_from(&invalid).unwrap_err();
+/// assert_eq!(err.valid_up_to(), 2);
+/// ```
+impl<'a, T: Borrow<str>, T2: Borrow<[u8]>> TryFrom<&'a NoStdCow<'_, T2, [u8]>> for NoStdCow<'a, T, str> {
+    type Error = Utf8Error;
+    fn try_from(value: &'a NoStdCow<'_, T2, [u8]>) -> Result<Self, Self::Error> {
+        core::str::from_utf8(value.deref()).map(NoStdCow::Borrowed)
+    }
+}
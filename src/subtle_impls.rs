@@ -0,0 +1,31 @@
+use crate::NoStdCow;
+use core::borrow::Borrow;
+use core::ops::Deref;
+use subtle::ConstantTimeEq;
+
+impl<T: Borrow<[u8]>> NoStdCow<'_, T, [u8]> {
+    /// Compares the contents with `other` in constant time, for cows holding secrets
+    /// such as tokens or MACs.
+    ///
+    /// Forwards to [`subtle::ConstantTimeEq`] on the byte slices, so the time taken
+    /// doesn't depend on where the first differing byte is. The lengths are not
+    /// treated as secret, and contents of different lengths compare unequal
+    /// immediately.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let token: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Owned(b"s3cret".to_vec());
+    /// let inputs: [&[u8]; 4] = [b"s3cret", b"s3creT", b"s3cre", b""];
+    /// for input in inputs {
+    ///     let candidate: NoStdCow<'_, Vec<u8>, [u8]> = NoStdCow::Borrowed(input);
+    ///     assert_eq!(token.ct_eq(&candidate), token == candidate);
+    /// }
+    /// assert!(token.ct_eq(&NoStdCow::Borrowed(b"s3cret")));
+    /// assert!(!token.ct_eq(&NoStdCow::Borrowed(b"s3creT")));
+    /// ```
+    pub fn ct_eq(&self, other: &NoStdCow<'_, T, [u8]>) -> bool {
+        self.deref().ct_eq(other.deref()).into()
+    }
+}
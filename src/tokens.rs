@@ -0,0 +1,91 @@
+use crate::NoStdCow;
+use core::borrow::Borrow;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+/// A tokenizing iterator over a str cow with allocation-free lookahead, created by
+/// [`NoStdCow::peekable_tokens`].
+///
+/// Tokens are the non-empty substrings separated by characters matching the
+/// delimiter predicate. [`PeekableTokens::peek`] and [`Iterator::next`] yield
+/// borrowed cows and never allocate, so a parser only pays for ownership with
+/// [`PeekableTokens::next_owned`] once it commits to keeping a token.
+pub struct PeekableTokens<'s, T, F> {
+    rest: &'s str,
+    is_delim: F,
+    owned: PhantomData<fn() -> T>,
+}
+
+/// Shows the unconsumed input. The delimiter predicate is usually a closure, so it
+/// is omitted.
+impl<T, F> fmt::Debug for PeekableTokens<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PeekableTokens").field("rest", &self.rest).finish_non_exhaustive()
+    }
+}
+
+impl<'s, T: Borrow<str>, F: FnMut(char) -> bool> PeekableTokens<'s, T, F> {
+    /// Skips leading delimiters and returns the next token, without consuming it.
+    fn next_token(&mut self) -> Option<&'s str> {
+        let is_delim = &mut self.is_delim;
+        let start = self.rest.find(|c| !is_delim(c))?;
+        self.rest = &self.rest[start..];
+        let end = self.rest.find(&mut self.is_delim).unwrap_or(self.rest.len());
+        Some(&self.rest[..end])
+    }
+    /// Returns the next token as a borrowed cow without consuming it.
+    ///
+    /// Repeated calls return the same token until it is consumed.
+    pub fn peek(&mut self) -> Option<NoStdCow<'s, T, str>> {
+        self.next_token().map(NoStdCow::Borrowed)
+    }
+    /// Consumes the next token and returns it as an owned `T`.
+    pub fn next_owned(&mut self) -> Option<T>
+    where
+        T: for<'t> From<&'t str>,
+    {
+        self.next().map(|token| T::from(token.deref()))
+    }
+}
+
+impl<'s, T: Borrow<str>, F: FnMut(char) -> bool> Iterator for PeekableTokens<'s, T, F> {
+    type Item = NoStdCow<'s, T, str>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.next_token()?;
+        self.rest = &self.rest[token.len()..];
+        Some(NoStdCow::Borrowed(token))
+    }
+}
+
+impl<T: Borrow<str>> NoStdCow<'_, T, str> {
+    /// Returns an iterator over the non-empty tokens separated by characters
+    /// matching `is_delim`, with support for peeking.
+    ///
+    /// This yields the same tokens as [`NoStdCow::tokens`], but the returned
+    /// [`PeekableTokens`] also allows looking ahead without allocating and taking
+    /// owned tokens on demand, which suits recursive-descent parsers.
+    ///
+    /// # Example
+    /// ```
+    /// use nostd_cow::NoStdCow;
+    ///
+    /// let source: NoStdCow<'_, String, str> = NoStdCow::Borrowed("let  x = 42");
+    /// let mut tokens = source.peekable_tokens(char::is_whitespace);
+    /// assert_eq!(tokens.peek(), Some(NoStdCow::Borrowed("let")));
+    /// assert!(tokens.peek().unwrap().is_borrowed());
+    /// assert_eq!(format!("{tokens:?}"), r#"PeekableTokens { rest: "let  x = 42", .. }"#);
+    /// if tokens.peek().as_deref() == Some("let") {
+    ///     tokens.next();
+    /// }
+    /// let name: Option<String> = tokens.next_owned();
+    /// assert_eq!(name.as_deref(), Some("x"));
+    /// assert_eq!(tokens.next(), Some(NoStdCow::Borrowed("=")));
+    /// assert_eq!(tokens.next(), Some(NoStdCow::Borrowed("42")));
+    /// assert_eq!(tokens.peek(), None);
+    /// assert_eq!(tokens.next(), None);
+    /// ```
+    pub fn peekable_tokens<F: FnMut(char) -> bool>(&self, is_delim: F) -> PeekableTokens<'_, T, F> {
+        PeekableTokens { rest: self.deref(), is_delim, owned: PhantomData }
+    }
+}